@@ -0,0 +1,91 @@
+use pulldown_cmark::{Event, Parser, Tag};
+
+/// A labelled contact detail from a `- **Label:** value` list item in `contact.md`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContactField {
+    pub label: String,
+    pub value: String,
+    pub url: Option<String>,
+}
+
+impl ContactField {
+    /// Returns true if the field's label matches `name`, ignoring case
+    pub fn is_named(&self, name: &str) -> bool {
+        self.label.eq_ignore_ascii_case(name.trim())
+    }
+}
+
+/// Extracts the labelled contact details from contact markdown
+pub fn parse_contact_fields(content: &str) -> Vec<ContactField> {
+    let mut fields = Vec::new();
+    let mut in_item = false;
+    let mut in_strong = false;
+    let mut label = String::new();
+    let mut value = String::new();
+    let mut url = None;
+
+    for event in Parser::new(content) {
+        match event {
+            Event::Start(Tag::Item) => {
+                in_item = true;
+                label.clear();
+                value.clear();
+                url = None;
+            }
+            Event::End(Tag::Item) => {
+                in_item = false;
+                let field_label = label.trim().trim_end_matches(':').trim();
+                let field_value = value.trim();
+                if !field_label.is_empty() && !field_value.is_empty() {
+                    fields.push(ContactField {
+                        label: field_label.to_string(),
+                        value: field_value.to_string(),
+                        url: url.take(),
+                    });
+                }
+            }
+            Event::Start(Tag::Strong) if in_item && value.is_empty() => in_strong = true,
+            Event::End(Tag::Strong) => in_strong = false,
+            Event::Start(Tag::Link(_, link_url, _)) if in_item => url = Some(link_url.to_string()),
+            Event::Text(text) | Event::Code(text) if in_item => {
+                if in_strong {
+                    label.push_str(&text);
+                } else {
+                    value.push_str(&text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fields
+}
+
+/// Returns the bold sign-off at the end of contact markdown, e.g. `**David Parker**`
+pub fn parse_signature(content: &str) -> Option<String> {
+    let mut signature = None;
+    let mut paragraph_start = false;
+    let mut in_strong = false;
+    let mut text = String::new();
+
+    for event in Parser::new(content) {
+        match event {
+            Event::Start(Tag::Paragraph) => paragraph_start = true,
+            Event::Start(Tag::Strong) if paragraph_start => {
+                in_strong = true;
+                text.clear();
+            }
+            Event::End(Tag::Strong) if in_strong => {
+                in_strong = false;
+                paragraph_start = false;
+                if !text.trim().is_empty() {
+                    signature = Some(text.trim().to_string());
+                }
+            }
+            Event::Text(value) if in_strong => text.push_str(&value),
+            _ => paragraph_start = false,
+        }
+    }
+
+    signature
+}
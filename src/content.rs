@@ -0,0 +1,228 @@
+use serde::de::DeserializeOwned;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+/// Environment variable that points at an external content directory
+pub const CONTENT_DIR_ENV: &str = "HIRE_DAVID_PARKER_CONTENT_DIR";
+
+const APP_DIR_NAME: &str = "hire-david-parker";
+
+static ACTIVE_SOURCE: RwLock<Option<ContentSource>> = RwLock::new(None);
+
+macro_rules! embed_content {
+    ($($filename:literal),* $(,)?) => {
+        &[$(($filename, include_str!(concat!("static/content/", $filename)))),*]
+    };
+}
+
+/// The default content pack, compiled into the binary
+pub const EMBEDDED_CONTENT: &[(&str, &str)] = embed_content![
+    "about.md",
+    "contact.md",
+    "projects.md",
+    "skills.md",
+    "timeline.md",
+    "welcome.md",
+    "projects.json",
+    "skills.json",
+    "timeline.json",
+];
+
+/// Where resume content files are read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContentSource {
+    /// A user supplied directory, falling back to the embedded content per file
+    Directory(PathBuf),
+    /// The content pack compiled into the binary
+    Embedded,
+}
+
+impl ContentSource {
+    /// Resolves the content source from a `--content-dir` flag, the
+    /// `HIRE_DAVID_PARKER_CONTENT_DIR` environment variable, or the XDG data
+    /// directory, in that order, falling back to the embedded content
+    pub fn resolve(content_dir: Option<PathBuf>) -> Self {
+        if let Some(dir) = content_dir {
+            return ContentSource::Directory(dir);
+        }
+
+        if let Some(dir) = env::var_os(CONTENT_DIR_ENV).filter(|value| !value.is_empty()) {
+            return ContentSource::Directory(PathBuf::from(dir));
+        }
+
+        match xdg_content_dir() {
+            Some(dir) if dir.is_dir() => ContentSource::Directory(dir),
+            _ => ContentSource::Embedded,
+        }
+    }
+
+    /// Reads a content file, using the embedded copy when an external directory lacks it
+    pub fn read(&self, filename: &str) -> io::Result<String> {
+        match self {
+            ContentSource::Directory(dir) => match fs::read_to_string(dir.join(filename)) {
+                Err(e) if e.kind() == io::ErrorKind::NotFound => read_embedded(filename),
+                result => result,
+            },
+            ContentSource::Embedded => read_embedded(filename),
+        }
+    }
+}
+
+/// Errors raised while loading resume content
+#[derive(Debug)]
+pub enum ContentError {
+    /// The content file does not exist in any content source
+    Missing { file: String },
+    /// The content file exists but could not be read
+    Io { file: String, source: io::Error },
+    /// The content file is not valid JSON
+    Parse { file: String, line: usize, column: usize, message: String },
+    /// The content file is valid JSON but does not match the expected structure
+    Schema { file: String, message: String },
+}
+
+impl ContentError {
+    /// Builds the error for a failed read of `file`
+    pub fn from_io(file: &str, source: io::Error) -> Self {
+        match source.kind() {
+            io::ErrorKind::NotFound => ContentError::Missing { file: file.to_string() },
+            _ => ContentError::Io { file: file.to_string(), source },
+        }
+    }
+
+    /// Builds the error for a failed JSON deserialization of `file`
+    pub fn from_json(file: &str, error: serde_json::Error) -> Self {
+        match error.classify() {
+            serde_json::error::Category::Data => ContentError::Schema {
+                file: file.to_string(),
+                message: error.to_string(),
+            },
+            _ => ContentError::Parse {
+                file: file.to_string(),
+                line: error.line(),
+                column: error.column(),
+                message: error.to_string(),
+            },
+        }
+    }
+
+    /// Builds the error for a failed TOML deserialization of `file` from `source`
+    pub fn from_toml(file: &str, source: &str, error: toml::de::Error) -> Self {
+        let Some(span) = error.span() else {
+            return ContentError::schema(file, error.message());
+        };
+
+        let before = &source[..span.start.min(source.len())];
+        ContentError::Parse {
+            file: file.to_string(),
+            line: before.lines().count().max(1) + usize::from(before.ends_with('\n')),
+            column: before.rsplit('\n').next().unwrap_or_default().chars().count() + 1,
+            message: error.message().to_string(),
+        }
+    }
+
+    /// Builds a schema violation for `file`
+    pub fn schema(file: &str, message: impl Into<String>) -> Self {
+        ContentError::Schema { file: file.to_string(), message: message.into() }
+    }
+
+    /// Returns the name of the content file the error refers to
+    pub fn file(&self) -> &str {
+        match self {
+            ContentError::Missing { file }
+            | ContentError::Io { file, .. }
+            | ContentError::Parse { file, .. }
+            | ContentError::Schema { file, .. } => file,
+        }
+    }
+}
+
+impl fmt::Display for ContentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContentError::Missing { file } => write!(f, "content file '{}' was not found", file),
+            ContentError::Io { file, source } => write!(f, "failed to read '{}': {}", file, source),
+            ContentError::Parse { file, line, column, message } => {
+                let format = if file.ends_with(".toml") { "TOML" } else { "JSON" };
+                write!(f, "invalid {} in '{}' at line {}, column {}: {}", format, file, line, column, message)
+            }
+            ContentError::Schema { file, message } => write!(f, "unexpected structure in '{}': {}", file, message),
+        }
+    }
+}
+
+impl std::error::Error for ContentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ContentError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the XDG data location for content, e.g. `~/.local/share/hire-david-parker/content`
+pub fn xdg_content_dir() -> Option<PathBuf> {
+    let data_home = env::var_os("XDG_DATA_HOME")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".local").join("share")))?;
+
+    Some(data_home.join(APP_DIR_NAME).join("content"))
+}
+
+/// Returns the XDG config location for the app, e.g. `~/.config/hire-david-parker`
+pub(crate) fn xdg_config_dir() -> Option<PathBuf> {
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+
+    Some(config_home.join(APP_DIR_NAME))
+}
+
+/// Sets the content source used by the loaders in this crate
+pub fn set_content_source(source: ContentSource) {
+    if let Ok(mut active) = ACTIVE_SOURCE.write() {
+        *active = Some(source);
+    }
+}
+
+/// Returns the content source used by the loaders in this crate
+pub fn content_source() -> ContentSource {
+    ACTIVE_SOURCE
+        .read()
+        .ok()
+        .and_then(|active| active.clone())
+        .unwrap_or(ContentSource::Embedded)
+}
+
+/// Reads a content file from the active content source
+pub fn read_content_file(filename: &str) -> Result<String, ContentError> {
+    content_source()
+        .read(filename)
+        .map_err(|e| ContentError::from_io(filename, e))
+}
+
+/// Reads and deserializes a JSON content file from the active content source
+pub fn read_json_file<T: DeserializeOwned>(filename: &str) -> Result<T, ContentError> {
+    let content = read_content_file(filename)?;
+    serde_json::from_str(&content).map_err(|e| ContentError::from_json(filename, e))
+}
+
+/// Returns an embedded content file by name
+pub fn embedded_file(filename: &str) -> Option<&'static str> {
+    EMBEDDED_CONTENT
+        .iter()
+        .find(|(name, _)| *name == filename)
+        .map(|(_, content)| *content)
+}
+
+fn read_embedded(filename: &str) -> io::Result<String> {
+    embedded_file(filename)
+        .map(str::to_string)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("no embedded content named '{}'", filename)))
+}
@@ -0,0 +1,114 @@
+use super::Resume;
+use crate::text::{
+    contact_fields_to_text, markdown_to_text, project_links_to_text, skill_bar, skills_to_text, timeline_to_text,
+    wrap_text,
+};
+use crate::tui::models::format_duration;
+use crate::tui::theme::ColorDepth;
+use crate::{TimelineEvent, YearMonth};
+use unicode_width::UnicodeWidthStr;
+
+/// Renders every section as one plain-text document wrapped at `width` columns
+pub fn to_text(resume: &Resume, width: usize) -> String {
+    let events: Vec<&TimelineEvent> = resume.timeline.iter().collect();
+    let name = resume.name.as_deref().unwrap_or("Resume");
+
+    let sections = [
+        format!("{}\n{}\n\n{}", name, "=".repeat(name.width()), contact_fields_to_text(&resume.contact_fields, ColorDepth::Monochrome)),
+        text_section("About", &markdown_to_text(&resume.about, ColorDepth::Monochrome)),
+        text_section("Skills", &skills_to_text(&resume.skills, ColorDepth::Monochrome)),
+        text_section(
+            "Projects",
+            &format!(
+                "{}\n\nLinks\n{}",
+                markdown_to_text(&resume.projects, ColorDepth::Monochrome),
+                project_links_to_text(&resume.project_links, ColorDepth::Monochrome)
+            ),
+        ),
+        text_section("Timeline", &timeline_to_text(&events, ColorDepth::Monochrome)),
+        text_section("Contact", &markdown_to_text(&resume.contact, ColorDepth::Monochrome)),
+    ];
+
+    format!("{}\n", wrap_text(&sections.join("\n\n\n"), width))
+}
+
+/// Renders every section as one Markdown document wrapped at `width` columns
+pub fn to_markdown(resume: &Resume, width: usize) -> String {
+    let today = YearMonth::today();
+    let name = resume.name.as_deref().unwrap_or("Resume");
+
+    let contact_fields: Vec<String> = resume
+        .contact_fields
+        .iter()
+        .map(|field| match &field.url {
+            Some(url) => format!("- **{}:** [{}]({})", field.label, field.value, url),
+            None => format!("- **{}:** {}", field.label, field.value),
+        })
+        .collect();
+
+    let skills: Vec<String> = resume
+        .skills
+        .categories
+        .iter()
+        .map(|category| {
+            let name_width = category.skills.iter().map(|skill| skill.name.width()).max().unwrap_or(0);
+            let bars: Vec<String> = category
+                .skills
+                .iter()
+                .map(|skill| format!("{:<width$}  {}", skill.name, skill_bar(skill.level), width = name_width))
+                .collect();
+            format!("### {}\n\n```text\n{}\n```", category.name, bars.join("\n"))
+        })
+        .collect();
+
+    let links: Vec<String> = resume
+        .project_links
+        .iter()
+        .map(|link| format!("- [{}]({})", link.text, link.url))
+        .collect();
+
+    let events: Vec<String> = resume.timeline.iter().map(|event| event_to_markdown(event, today)).collect();
+
+    let sections = [
+        format!("# {}\n\n{}", name, contact_fields.join("\n")),
+        format!("## About\n\n{}", resume.about.trim()),
+        format!("## Skills\n\n{}", skills.join("\n\n")),
+        format!("## Projects\n\n{}\n\n### Links\n\n{}", resume.projects.trim(), links.join("\n")),
+        format!("## Timeline\n\n{}", events.join("\n\n")),
+        format!("## Contact\n\n{}", resume.contact.trim()),
+    ];
+
+    format!("{}\n", wrap_text(&sections.join("\n\n"), width))
+}
+
+fn text_section(title: &str, body: &str) -> String {
+    format!("{}\n{}\n\n{}", title.to_uppercase(), "-".repeat(title.width()), body)
+}
+
+fn event_to_markdown(event: &TimelineEvent, today: YearMonth) -> String {
+    let mut details = vec![format!("**{}**", event.organization), event.event_type.label().to_string()];
+    if let (Some(range), Some(months)) = (event.date_range_label(), event.duration_months(today)) {
+        details.push(format!("{} ({})", range, format_duration(months)));
+    }
+
+    let mut lines = vec![
+        format!("### {} - {}", event.year, event.title),
+        String::new(),
+        details.join(" · "),
+        String::new(),
+        event.description.clone(),
+    ];
+
+    let highlights: Vec<String> = event.highlights.iter().flatten().map(|h| format!("- {}", h.trim())).collect();
+    if !highlights.is_empty() {
+        lines.push(String::new());
+        lines.extend(highlights);
+    }
+
+    if let Some(technologies) = event.technologies.as_ref().filter(|techs| !techs.is_empty()) {
+        lines.push(String::new());
+        lines.push(format!("*Technologies:* {}", technologies.join(", ")));
+    }
+
+    lines.join("\n")
+}
@@ -0,0 +1,222 @@
+use super::Resume;
+use crate::tui::markdown::markdown_parser;
+use crate::tui::models::{format_duration, timeline_span_lanes, SkillsData};
+use crate::{ContactField, ProjectLink, TimelineEvent, TimelineType, YearMonth};
+use pulldown_cmark::escape::{escape_href, escape_html};
+use pulldown_cmark::html::push_html;
+
+const STYLESHEET: &str = include_str!("../static/export/resume.css");
+
+/// Renders the resume as a standalone HTML page with its stylesheet inlined
+pub fn to_html(resume: &Resume) -> String {
+    let name = resume.name.as_deref().unwrap_or("Resume");
+    let today = YearMonth::today();
+    let events: Vec<&TimelineEvent> = resume.timeline.iter().collect();
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    html.push_str(&format!("<title>{}</title>\n<style>\n{}</style>\n</head>\n<body>\n", escape(name), STYLESHEET));
+
+    html.push_str(&format!("<header>\n<h1>{}</h1>\n{}</header>\n<main>\n", escape(name), contact_list(&resume.contact_fields)));
+    html.push_str(&section("about", "About", &markdown_to_html(&resume.about)));
+    html.push_str(&section("skills", "Skills", &skills_to_html(&resume.skills)));
+    html.push_str(&section(
+        "projects",
+        "Projects",
+        &format!("{}{}", markdown_to_html(&resume.projects), project_links_to_html(&resume.project_links)),
+    ));
+    html.push_str(&section(
+        "timeline",
+        "Timeline",
+        &format!("{}{}", horizontal_timeline(&events, today), timeline_details(&events, today)),
+    ));
+    html.push_str(&section("contact", "Contact", &markdown_to_html(&resume.contact)));
+    html.push_str("</main>\n</body>\n</html>\n");
+
+    html
+}
+
+/// Renders markdown to HTML using the same parser as the TUI
+pub fn markdown_to_html(content: &str) -> String {
+    let mut html = String::new();
+    push_html(&mut html, markdown_parser(content));
+    html
+}
+
+fn section(id: &str, title: &str, body: &str) -> String {
+    format!("<section id=\"{}\">\n<h2>{}</h2>\n{}</section>\n", id, title, body)
+}
+
+fn contact_list(fields: &[ContactField]) -> String {
+    let items: String = fields
+        .iter()
+        .map(|field| {
+            let value = match &field.url {
+                Some(url) => link(url, &field.value),
+                None => escape(&field.value),
+            };
+            format!("<li><strong>{}:</strong> {}</li>\n", escape(&field.label), value)
+        })
+        .collect();
+
+    format!("<ul class=\"contact\">\n{}</ul>\n", items)
+}
+
+fn skills_to_html(skills_data: &SkillsData) -> String {
+    let mut html = String::new();
+
+    for category in &skills_data.categories {
+        html.push_str(&format!("<h3>{}</h3>\n", escape(&category.name)));
+        for skill in &category.skills {
+            let level = skill.level.min(100);
+            html.push_str(&format!(
+                "<div class=\"skill\"><span class=\"skill-name\">{name}</span>\
+                 <meter min=\"0\" max=\"100\" low=\"40\" high=\"75\" optimum=\"100\" value=\"{level}\">{level}%</meter>\
+                 <span class=\"skill-level\">{level}%</span></div>\n",
+                name = escape(&skill.name),
+                level = level
+            ));
+        }
+    }
+
+    html
+}
+
+fn project_links_to_html(links: &[ProjectLink]) -> String {
+    if links.is_empty() {
+        return String::new();
+    }
+
+    let items: String = links
+        .iter()
+        .map(|project| format!("<li>{}</li>\n", link(&project.url, &project.text)))
+        .collect();
+
+    format!("<h3>Links</h3>\n<ul class=\"project-links\">\n{}</ul>\n", items)
+}
+
+fn horizontal_timeline(events: &[&TimelineEvent], today: YearMonth) -> String {
+    if events.is_empty() {
+        return String::new();
+    }
+
+    let (lanes, lane_count) = timeline_span_lanes(events, today);
+    let first_month = events.iter().map(|e| e.month_range(today).0).min().unwrap_or(0);
+    let last_month = events.iter().map(|e| e.month_range(today).1).max().unwrap_or(0);
+    let month_span = f64::from((last_month - first_month).max(1));
+    let percent = |month: u32| f64::from(month - first_month) * 100.0 / month_span;
+
+    let mut html = format!("<div class=\"timeline\" style=\"--lanes: {}\">\n<div class=\"axis\"></div>\n", lane_count.max(1));
+
+    for (i, event) in events.iter().enumerate() {
+        let (start_month, end_month) = event.month_range(today);
+        let class = type_class(event.event_type);
+        let left = percent(start_month);
+
+        if let Some(lane) = lanes[i] {
+            html.push_str(&format!(
+                "<span class=\"bar {}\" style=\"left: {:.2}%; width: {:.2}%; top: {:.2}rem\"></span>\n",
+                class,
+                left,
+                percent(end_month) - left,
+                lane as f64 * 0.75
+            ));
+        }
+
+        html.push_str(&format!(
+            "<a class=\"marker {}\" href=\"#event-{}\" style=\"left: {:.2}%\" title=\"{}\">{}</a>\n",
+            class,
+            i + 1,
+            left,
+            escape(&event.title),
+            event.event_type.symbol()
+        ));
+        html.push_str(&format!(
+            "<span class=\"year {}\" style=\"left: {:.2}%\">{}</span>\n",
+            class, left, event.year
+        ));
+    }
+
+    html.push_str("</div>\n");
+    html.push_str(&timeline_legend(events));
+    html
+}
+
+fn timeline_legend(events: &[&TimelineEvent]) -> String {
+    let items: String = TimelineType::ALL
+        .into_iter()
+        .filter(|event_type| events.iter().any(|event| event.event_type == *event_type))
+        .map(|event_type| {
+            format!(
+                "<span class=\"{}\">{} {}</span>\n",
+                type_class(event_type),
+                event_type.symbol(),
+                event_type.label()
+            )
+        })
+        .collect();
+
+    format!("<div class=\"legend\">\n{}</div>\n", items)
+}
+
+fn timeline_details(events: &[&TimelineEvent], today: YearMonth) -> String {
+    let mut html = String::from("<ol class=\"events\">\n");
+
+    for (i, event) in events.iter().enumerate() {
+        let mut meta = vec![event.year.to_string()];
+        if let (Some(range), Some(months)) = (event.date_range_label(), event.duration_months(today)) {
+            meta.push(format!("{} ({})", range, format_duration(months)));
+        }
+        meta.push(event.event_type.label().to_string());
+
+        html.push_str(&format!(
+            "<li id=\"event-{}\" class=\"event {}\">\n<h3>{} {} <small>| {}</small></h3>\n<p class=\"meta\">{}</p>\n<p>{}</p>\n",
+            i + 1,
+            type_class(event.event_type),
+            event.event_type.symbol(),
+            escape(&event.title),
+            escape(&event.organization),
+            escape(&meta.join(" · ")),
+            escape(&event.description)
+        ));
+
+        let highlights: String = event
+            .highlights
+            .iter()
+            .flatten()
+            .map(|highlight| format!("<li>{}</li>\n", escape(highlight.trim())))
+            .collect();
+        if !highlights.is_empty() {
+            html.push_str(&format!("<ul>\n{}</ul>\n", highlights));
+        }
+
+        if let Some(technologies) = event.technologies.as_ref().filter(|techs| !techs.is_empty()) {
+            html.push_str(&format!(
+                "<p class=\"technologies\">Technologies: {}</p>\n",
+                escape(&technologies.join(", "))
+            ));
+        }
+
+        html.push_str("</li>\n");
+    }
+
+    html.push_str("</ol>\n");
+    html
+}
+
+fn type_class(event_type: TimelineType) -> String {
+    event_type.label().to_ascii_lowercase()
+}
+
+fn link(url: &str, text: &str) -> String {
+    let mut href = String::new();
+    let _ = escape_href(&mut href, url);
+    format!("<a href=\"{}\">{}</a>", href, escape(text))
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    let _ = escape_html(&mut escaped, text);
+    escaped
+}
@@ -0,0 +1,581 @@
+use super::Resume;
+use crate::text::markdown_to_text;
+use crate::tui::models::{Skill, SkillCategory, SkillsData};
+use crate::tui::theme::ColorDepth;
+use crate::{ContentError, ProjectLink, ProjectLinks, TimelineDate, TimelineEvent, TimelineType};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The JSON Resume schema the export conforms to
+pub const SCHEMA_URL: &str = "https://raw.githubusercontent.com/jsonresume/resume-schema/v1.0.0/schema.json";
+
+const RESUME_FILE: &str = "resume.json";
+
+/// A resume document in the JSON Resume format (jsonresume.org)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JsonResume {
+    #[serde(rename = "$schema", default, skip_serializing_if = "String::is_empty")]
+    pub schema: String,
+    #[serde(default)]
+    pub basics: Basics,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub work: Vec<Work>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub education: Vec<Education>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub certificates: Vec<Certificate>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<JsonSkill>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<Project>,
+}
+
+/// The `basics` section: name, contact details, and summary
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Basics {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub label: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub email: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub phone: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub url: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub summary: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub profiles: Vec<Profile>,
+}
+
+/// A social profile such as GitHub or LinkedIn
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    #[serde(default)]
+    pub network: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub username: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub url: String,
+}
+
+/// A `work` entry, exported from career timeline events
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Work {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub position: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub start_date: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub end_date: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub summary: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+}
+
+/// An `education` entry, exported from education timeline events
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Education {
+    #[serde(default)]
+    pub institution: String,
+    #[serde(default)]
+    pub area: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub start_date: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub end_date: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub summary: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+}
+
+/// A `certificates` entry, exported from certification timeline events
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Certificate {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub date: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub issuer: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub url: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub summary: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+}
+
+/// A `skills` entry; each skill is exported on its own with its category as a keyword
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JsonSkill {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub level: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+}
+
+/// A `projects` entry, exported from project links and project timeline events
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Project {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub entity: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub url: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub start_date: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub end_date: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+}
+
+/// Serializes the resume as a pretty-printed `resume.json` document
+pub fn to_json(resume: &Resume) -> Result<String, ContentError> {
+    serde_json::to_string_pretty(&JsonResume::from(resume)).map_err(|e| ContentError::from_json(RESUME_FILE, e))
+}
+
+/// The outcome of an import: the files written and the entries left out for lack of a date
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Imported {
+    pub written: Vec<PathBuf>,
+    pub skipped: Vec<String>,
+}
+
+/// Builds a content directory from a `resume.json` document, overwriting its content files
+pub fn import(json: &str, dir: &Path) -> Result<Imported, ContentError> {
+    let resume: JsonResume = serde_json::from_str(json).map_err(|e| ContentError::from_json(RESUME_FILE, e))?;
+    let files = resume.to_content_files()?;
+
+    fs::create_dir_all(dir).map_err(|e| ContentError::from_io(&dir.display().to_string(), e))?;
+
+    let mut written = Vec::new();
+    for (filename, content) in files {
+        let path = dir.join(filename);
+        fs::write(&path, content).map_err(|e| ContentError::from_io(filename, e))?;
+        written.push(path);
+    }
+
+    Ok(Imported { written, skipped: resume.undated_entries() })
+}
+
+impl From<&Resume> for JsonResume {
+    fn from(resume: &Resume) -> Self {
+        let field = |name: &str| {
+            resume
+                .contact_fields
+                .iter()
+                .find(|field| field.is_named(name))
+                .map(|field| field.value.clone())
+                .unwrap_or_default()
+        };
+
+        let profiles = resume
+            .contact_fields
+            .iter()
+            .filter_map(|field| {
+                let url = field.url.as_ref().filter(|url| !url.starts_with("mailto:"))?;
+                Some(Profile {
+                    network: field.label.clone(),
+                    username: url.trim_end_matches('/').rsplit('/').next().unwrap_or_default().to_string(),
+                    url: url.clone(),
+                })
+            })
+            .collect();
+
+        let mut json_resume = JsonResume {
+            schema: SCHEMA_URL.to_string(),
+            basics: Basics {
+                name: resume.name.clone().unwrap_or_default(),
+                email: field("email"),
+                phone: field("phone"),
+                summary: markdown_to_text(&resume.about, ColorDepth::Monochrome),
+                profiles,
+                ..Basics::default()
+            },
+            ..JsonResume::default()
+        };
+
+        for category in &resume.skills.categories {
+            for skill in &category.skills {
+                json_resume.skills.push(JsonSkill {
+                    name: skill.name.clone(),
+                    level: level_label(skill.level).to_string(),
+                    keywords: vec![category.name.clone()],
+                });
+            }
+        }
+
+        for event in &resume.timeline {
+            let (start_date, end_date) = export_dates(event);
+            let highlights: Vec<String> = event.highlights.iter().flatten().map(|h| h.trim().to_string()).collect();
+            let keywords = event.technologies.clone().unwrap_or_default();
+
+            match event.event_type {
+                TimelineType::Career | TimelineType::Other => json_resume.work.push(Work {
+                    name: event.organization.clone(),
+                    position: event.title.clone(),
+                    start_date,
+                    end_date,
+                    summary: event.description.clone(),
+                    highlights,
+                    keywords,
+                }),
+                TimelineType::Education => json_resume.education.push(Education {
+                    institution: event.organization.clone(),
+                    area: event.title.clone(),
+                    start_date,
+                    end_date,
+                    summary: event.description.clone(),
+                    highlights,
+                    keywords,
+                }),
+                TimelineType::Certification => json_resume.certificates.push(Certificate {
+                    name: event.title.clone(),
+                    date: start_date,
+                    issuer: event.organization.clone(),
+                    url: String::new(),
+                    summary: event.description.clone(),
+                    highlights,
+                    keywords,
+                }),
+                TimelineType::Project => json_resume.projects.push(Project {
+                    name: event.title.clone(),
+                    entity: event.organization.clone(),
+                    description: event.description.clone(),
+                    url: String::new(),
+                    start_date,
+                    end_date,
+                    highlights,
+                    keywords,
+                }),
+            }
+        }
+
+        for link in &resume.project_links {
+            json_resume.projects.push(Project {
+                name: link.text.clone(),
+                url: link.url.clone(),
+                ..Project::default()
+            });
+        }
+
+        json_resume
+    }
+}
+
+impl JsonResume {
+    /// Converts the document into content files, keyed by filename
+    pub fn to_content_files(&self) -> Result<Vec<(&'static str, String)>, ContentError> {
+        let skills = self.skills_data();
+        let timeline = self.timeline_events()?;
+        let links = ProjectLinks {
+            links: self
+                .projects
+                .iter()
+                .filter(|project| !project.url.trim().is_empty())
+                .map(|project| ProjectLink { text: project.name.clone(), url: project.url.clone() })
+                .collect(),
+        };
+
+        let to_json = |value: serde_json::Result<String>| value.map_err(|e| ContentError::from_json(RESUME_FILE, e));
+
+        Ok(vec![
+            ("welcome.md", self.welcome_markdown()),
+            ("about.md", format!("{}\n", self.basics.summary.trim())),
+            ("contact.md", self.contact_markdown()),
+            ("skills.md", skills_markdown(&skills)),
+            ("skills.json", to_json(serde_json::to_string_pretty(&skills))?),
+            ("projects.md", self.projects_markdown()),
+            ("projects.json", to_json(serde_json::to_string_pretty(&links))?),
+            ("timeline.md", String::from("## Timeline\n\nCareer, education, certifications, and projects over the years.\n")),
+            ("timeline.json", to_json(serde_json::to_string_pretty(&timeline))?),
+        ])
+    }
+
+    fn welcome_markdown(&self) -> String {
+        let owner = match self.basics.name.trim() {
+            "" => String::from("this"),
+            name => format!("{}'s", name),
+        };
+
+        format!(
+            "## Welcome\n\nThanks for checking out {} interactive resume! Pick a section from the menu to get started, and the footer shows the keys for each screen.\n",
+            owner
+        )
+    }
+
+    fn contact_markdown(&self) -> String {
+        let mut lines = Vec::new();
+        if !self.basics.email.is_empty() {
+            lines.push(format!("-   **Email:** {}", self.basics.email));
+        }
+        if !self.basics.phone.is_empty() {
+            lines.push(format!("-   **Phone:** {}", self.basics.phone));
+        }
+        if !self.basics.url.is_empty() {
+            lines.push(format!("-   **Website:** [{}]({})", display_url(&self.basics.url), self.basics.url));
+        }
+        for profile in &self.basics.profiles {
+            match profile.url.as_str() {
+                "" => lines.push(format!("-   **{}:** {}", profile.network, profile.username)),
+                url => lines.push(format!("-   **{}:** [{}]({})", profile.network, display_url(url), url)),
+            }
+        }
+
+        let mut content = lines.join("\n");
+        if !self.basics.name.is_empty() {
+            content.push_str(&format!("\n\n**{}**", self.basics.name));
+        }
+        content.push('\n');
+        content
+    }
+
+    fn projects_markdown(&self) -> String {
+        let sections: Vec<String> = self
+            .projects
+            .iter()
+            .map(|project| match project.description.trim() {
+                "" => format!("### {}", project.name),
+                description => format!("### {}\n\n{}", project.name, description),
+            })
+            .collect();
+
+        format!("{}\n", sections.join("\n\n"))
+    }
+
+    fn skills_data(&self) -> SkillsData {
+        let mut skills_data = SkillsData::default();
+
+        for skill in &self.skills {
+            let category_name = skill.keywords.first().cloned().unwrap_or_else(|| String::from("Skills"));
+            let index = match skills_data.categories.iter().position(|c| c.name == category_name) {
+                Some(index) => index,
+                None => {
+                    skills_data.categories.push(SkillCategory { name: category_name, skills: Vec::new() });
+                    skills_data.categories.len() - 1
+                }
+            };
+
+            skills_data.categories[index].skills.push(Skill {
+                name: skill.name.clone(),
+                level: parse_level(&skill.level),
+            });
+        }
+
+        skills_data
+    }
+
+    /// Describes the work, education and certificate entries skipped because they have no date
+    pub fn undated_entries(&self) -> Vec<String> {
+        let work = self.work.iter().filter(|work| is_undated(&work.start_date)).map(|work| (&work.position, &work.name));
+        let education = self
+            .education
+            .iter()
+            .filter(|education| is_undated(&education.start_date))
+            .map(|education| (&education.area, &education.institution));
+        let certificates = self
+            .certificates
+            .iter()
+            .filter(|certificate| is_undated(&certificate.date))
+            .map(|certificate| (&certificate.name, &certificate.issuer));
+
+        work.chain(education)
+            .chain(certificates)
+            .map(|(title, organization)| match organization.trim() {
+                "" => format!("'{}' has no date", title),
+                organization => format!("'{}' ({}) has no date", title, organization),
+            })
+            .collect()
+    }
+
+    fn timeline_events(&self) -> Result<Vec<TimelineEvent>, ContentError> {
+        let mut events = Vec::new();
+
+        for work in self.work.iter().filter(|work| !is_undated(&work.start_date)) {
+            events.push(import_event(
+                TimelineType::Career,
+                &work.position,
+                &work.name,
+                &work.summary,
+                (&work.start_date, &work.end_date),
+                &work.highlights,
+                &work.keywords,
+            )?);
+        }
+        for education in self.education.iter().filter(|education| !is_undated(&education.start_date)) {
+            events.push(import_event(
+                TimelineType::Education,
+                &education.area,
+                &education.institution,
+                &education.summary,
+                (&education.start_date, &education.end_date),
+                &education.highlights,
+                &education.keywords,
+            )?);
+        }
+        for certificate in self.certificates.iter().filter(|certificate| !is_undated(&certificate.date)) {
+            events.push(import_event(
+                TimelineType::Certification,
+                &certificate.name,
+                &certificate.issuer,
+                &certificate.summary,
+                (&certificate.date, &certificate.date),
+                &certificate.highlights,
+                &certificate.keywords,
+            )?);
+        }
+        for project in self.projects.iter().filter(|project| !is_undated(&project.start_date)) {
+            events.push(import_event(
+                TimelineType::Project,
+                &project.name,
+                &project.entity,
+                &project.description,
+                (&project.start_date, &project.end_date),
+                &project.highlights,
+                &project.keywords,
+            )?);
+        }
+
+        events.sort_by_key(|event| (event.year, event.start));
+        Ok(events)
+    }
+}
+
+fn skills_markdown(skills: &SkillsData) -> String {
+    let lines: Vec<String> = skills
+        .categories
+        .iter()
+        .map(|category| {
+            let names: Vec<&str> = category.skills.iter().map(|skill| skill.name.as_str()).collect();
+            format!("-   **{}:** {}", category.name, names.join(", "))
+        })
+        .collect();
+
+    format!("## Skills\n\n{}\n", lines.join("\n"))
+}
+
+fn export_dates(event: &TimelineEvent) -> (String, String) {
+    match (event.start, event.end) {
+        (None, _) => (event.year.to_string(), String::new()),
+        (Some(start), None) => (start.to_string(), start.to_string()),
+        (Some(start), Some(TimelineDate::Present)) => (start.to_string(), String::new()),
+        (Some(start), Some(end)) => (start.to_string(), end.to_string()),
+    }
+}
+
+fn is_undated(date: &str) -> bool {
+    date.trim().is_empty()
+}
+
+fn import_event(
+    event_type: TimelineType,
+    title: &str,
+    organization: &str,
+    description: &str,
+    (start_date, end_date): (&str, &str),
+    highlights: &[String],
+    keywords: &[String],
+) -> Result<TimelineEvent, ContentError> {
+    let (year, start, end) = import_dates(title, start_date, end_date)?;
+
+    Ok(TimelineEvent {
+        year,
+        event_type,
+        title: title.to_string(),
+        organization: organization.to_string(),
+        description: description.to_string(),
+        highlights: Some(highlights.to_vec()).filter(|h| !h.is_empty()),
+        technologies: Some(keywords.to_vec()).filter(|k| !k.is_empty()),
+        start,
+        end,
+    })
+}
+
+fn import_dates(
+    title: &str,
+    start_date: &str,
+    end_date: &str,
+) -> Result<(u16, Option<TimelineDate>, Option<TimelineDate>), ContentError> {
+    let invalid = |date: &str| ContentError::schema(RESUME_FILE, format!("'{}' has an invalid date '{}'", title, date));
+    let start_date = start_date.trim();
+
+    if start_date.len() == 4 {
+        let year = start_date.parse().map_err(|_| invalid(start_date))?;
+        return Ok((year, None, None));
+    }
+
+    let month = |date: &str| TimelineDate::try_from(date.get(..7).unwrap_or(date).to_string()).map_err(|_| invalid(date));
+    let start = month(start_date)?;
+    let end = match end_date.trim() {
+        "" => Some(TimelineDate::Present),
+        date if date.len() == 4 => Some(month(&format!("{}-12", date))?),
+        date => Some(month(date)?).filter(|end| *end != start),
+    };
+
+    let TimelineDate::Month(first) = start else {
+        return Err(invalid(start_date));
+    };
+    if let Some(TimelineDate::Month(last)) = end {
+        if last < first {
+            return Err(ContentError::schema(RESUME_FILE, format!("'{}' ends before it starts", title)));
+        }
+    }
+
+    Ok((first.year, Some(start), end))
+}
+
+fn display_url(url: &str) -> &str {
+    url.trim_start_matches("https://").trim_start_matches("http://").trim_end_matches('/')
+}
+
+/// Maps a 0-100 skill level to a JSON Resume level name
+pub fn level_label(level: u8) -> &'static str {
+    match level {
+        0..=39 => "Beginner",
+        40..=64 => "Intermediate",
+        65..=84 => "Advanced",
+        85..=94 => "Expert",
+        _ => "Master",
+    }
+}
+
+/// Parses a JSON Resume level, accepting level names, numbers, or percentages
+pub fn parse_level(level: &str) -> u8 {
+    let level = level.trim();
+    if let Ok(value) = level.trim_end_matches('%').trim().parse::<u16>() {
+        return value.min(100) as u8;
+    }
+
+    match level.to_ascii_lowercase().as_str() {
+        "beginner" | "novice" => 25,
+        "intermediate" => 55,
+        "advanced" => 75,
+        "expert" => 90,
+        "master" => 100,
+        _ => 50,
+    }
+}
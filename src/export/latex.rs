@@ -0,0 +1,138 @@
+use super::template::{load_template, render_template, BundledTemplate};
+use super::Resume;
+use crate::tui::markdown::markdown_parser;
+use crate::tui::models::format_duration;
+use crate::{ContentError, YearMonth};
+use pulldown_cmark::{Event, Tag};
+
+/// LaTeX templates bundled into the binary; the first one is the default
+pub const TEMPLATES: &[BundledTemplate] = &[
+    ("classic", include_str!("../static/templates/classic.tex")),
+    ("compact", include_str!("../static/templates/compact.tex")),
+];
+
+/// Renders the resume as LaTeX source through a bundled template or a template file
+pub fn to_latex(resume: &Resume, template: Option<&str>) -> Result<String, ContentError> {
+    let (name, source) = load_template(TEMPLATES, template)?;
+    let today = YearMonth::today();
+
+    let contact: Vec<String> = resume
+        .contact_fields
+        .iter()
+        .map(|field| match &field.url {
+            Some(url) => format!("\\resumecontactlink{{{}}}{{{}}}{{{}}}", escape(&field.label), escape(&field.value), escape_url(url)),
+            None => format!("\\resumecontact{{{}}}{{{}}}", escape(&field.label), escape(&field.value)),
+        })
+        .collect();
+
+    let mut skills = Vec::new();
+    for category in &resume.skills.categories {
+        skills.push(format!("\\resumeskillcategory{{{}}}", escape(&category.name)));
+        for skill in &category.skills {
+            skills.push(format!("\\resumeskill{{{}}}{{{}}}", escape(&skill.name), skill.level.min(100)));
+        }
+    }
+
+    let project_links: Vec<String> = resume
+        .project_links
+        .iter()
+        .map(|link| format!("\\resumeprojectlink{{{}}}{{{}}}", escape(&link.text), escape_url(&link.url)))
+        .collect();
+
+    let mut timeline = Vec::new();
+    for event in &resume.timeline {
+        let dates = match (event.date_range_label(), event.duration_months(today)) {
+            (Some(range), Some(months)) => format!("{} ({})", range, format_duration(months)),
+            _ => String::new(),
+        };
+
+        timeline.push(format!(
+            "\\resumeevent{{{}}}{{{}}}{{{}}}{{{}}}{{{}}}{{{}}}",
+            event.year,
+            escape(&event.title),
+            escape(&event.organization),
+            event.event_type.label(),
+            escape(&dates),
+            escape(&event.description)
+        ));
+        for highlight in event.highlights.iter().flatten() {
+            timeline.push(format!("\\resumehighlight{{{}}}", escape(highlight.trim())));
+        }
+        if let Some(technologies) = event.technologies.as_ref().filter(|techs| !techs.is_empty()) {
+            timeline.push(format!("\\resumetechnologies{{{}}}", escape(&technologies.join(", "))));
+        }
+    }
+
+    render_template(
+        &name,
+        &source,
+        &[
+            ("name", escape(resume.name.as_deref().unwrap_or("Resume"))),
+            ("contact", contact.join("\n")),
+            ("about", markdown_to_latex(&resume.about)),
+            ("skills", skills.join("\n")),
+            ("projects", markdown_to_latex(&resume.projects)),
+            ("project_links", project_links.join("\n")),
+            ("timeline", timeline.join("\n")),
+        ],
+    )
+}
+
+/// Renders markdown as LaTeX using the same parser as the TUI
+pub fn markdown_to_latex(content: &str) -> String {
+    let mut latex = String::new();
+
+    for event in markdown_parser(content) {
+        match event {
+            Event::Start(Tag::Heading(..)) => latex.push_str("\\subsection*{"),
+            Event::End(Tag::Heading(..)) => latex.push_str("}\n\n"),
+            Event::End(Tag::Paragraph) => latex.push_str("\n\n"),
+            Event::Start(Tag::List(_)) => latex.push_str("\\begin{itemize}\n"),
+            Event::End(Tag::List(_)) => latex.push_str("\\end{itemize}\n\n"),
+            Event::Start(Tag::Item) => latex.push_str("\\item "),
+            Event::End(Tag::Item) => latex.push('\n'),
+            Event::Start(Tag::Strong) => latex.push_str("\\textbf{"),
+            Event::Start(Tag::Emphasis) => latex.push_str("\\emph{"),
+            Event::Start(Tag::Strikethrough) => latex.push('{'),
+            Event::Start(Tag::Link(_, url, _)) => latex.push_str(&format!("\\href{{{}}}{{", escape_url(&url))),
+            Event::End(Tag::Strong | Tag::Emphasis | Tag::Strikethrough | Tag::Link(..)) => latex.push('}'),
+            Event::Text(text) => latex.push_str(&escape(&text)),
+            Event::Code(text) => latex.push_str(&format!("\\texttt{{{}}}", escape(&text))),
+            Event::SoftBreak => latex.push('\n'),
+            Event::HardBreak => latex.push_str("\\\\\n"),
+            _ => {}
+        }
+    }
+
+    latex.trim_end().to_string()
+}
+
+/// Escapes text for LaTeX, dropping characters such as emoji that pdfLaTeX cannot typeset
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            '→' => escaped.push_str("\\textrightarrow{}"),
+            '←' => escaped.push_str("\\textleftarrow{}"),
+            '↑' => escaped.push_str("\\textuparrow{}"),
+            '↓' => escaped.push_str("\\textdownarrow{}"),
+            '•' => escaped.push_str("\\textbullet{}"),
+            c if u32::from(c) > 0xFFFF => {}
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn escape_url(url: &str) -> String {
+    url.replace('\\', "/").replace('%', "\\%").replace('#', "\\#")
+}
@@ -0,0 +1,140 @@
+pub mod document;
+pub mod html;
+pub mod json_resume;
+pub mod latex;
+pub mod template;
+pub mod typst;
+
+use crate::contact::parse_signature;
+use crate::tui::models::SkillsData;
+use crate::{
+    about, contact, load_project_links, projects, load_skills_data, load_timeline_data, parse_contact_fields, ContactField,
+    ContentError, ProjectLink, TimelineEvent,
+};
+use std::str::FromStr;
+
+/// Output formats supported by the `export` command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    JsonResume,
+    Html,
+    Text,
+    Markdown,
+    Latex,
+    Typst,
+}
+
+impl ExportFormat {
+    /// Every export format, in the order they are listed in help text
+    pub const ALL: [ExportFormat; 6] = [
+        ExportFormat::JsonResume,
+        ExportFormat::Html,
+        ExportFormat::Text,
+        ExportFormat::Markdown,
+        ExportFormat::Latex,
+        ExportFormat::Typst,
+    ];
+
+    /// Returns the name used to select the format on the command line
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExportFormat::JsonResume => "json-resume",
+            ExportFormat::Html => "html",
+            ExportFormat::Text => "txt",
+            ExportFormat::Markdown => "md",
+            ExportFormat::Latex => "latex",
+            ExportFormat::Typst => "typst",
+        }
+    }
+
+    /// Returns the file name used when the export is written into a directory
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            ExportFormat::JsonResume => "resume.json",
+            ExportFormat::Html => "index.html",
+            ExportFormat::Text => "resume.txt",
+            ExportFormat::Markdown => "resume.md",
+            ExportFormat::Latex => "resume.tex",
+            ExportFormat::Typst => "resume.typ",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        ExportFormat::ALL
+            .into_iter()
+            .find(|format| format.name().eq_ignore_ascii_case(value.trim()))
+            .ok_or_else(|| {
+                let names: Vec<&str> = ExportFormat::ALL.iter().map(|format| format.name()).collect();
+                format!("unknown export format '{}', expected one of: {}", value, names.join(", "))
+            })
+    }
+}
+
+/// Settings that apply to the export formats that use them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// Column to wrap plain-text and Markdown output at
+    pub width: usize,
+    /// Bundled template name or template file for LaTeX and Typst output
+    pub template: Option<String>,
+}
+
+impl ExportOptions {
+    /// Default wrapping width for plain-text and Markdown output
+    pub const DEFAULT_WIDTH: usize = 80;
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions { width: Self::DEFAULT_WIDTH, template: None }
+    }
+}
+
+/// Every section of the resume, loaded from the active content source
+#[derive(Debug, Clone)]
+pub struct Resume {
+    pub name: Option<String>,
+    pub about: String,
+    pub projects: String,
+    pub contact: String,
+    pub contact_fields: Vec<ContactField>,
+    pub skills: SkillsData,
+    pub project_links: Vec<ProjectLink>,
+    pub timeline: Vec<TimelineEvent>,
+}
+
+impl Resume {
+    /// Loads every section, failing on the first content error
+    pub fn load() -> Result<Self, ContentError> {
+        let contact = contact()?;
+        let mut timeline = load_timeline_data()?;
+        timeline.sort_by_key(|event| (event.year, event.start));
+
+        Ok(Resume {
+            name: parse_signature(&contact),
+            about: about()?,
+            projects: projects()?,
+            contact_fields: parse_contact_fields(&contact),
+            contact,
+            skills: load_skills_data()?,
+            project_links: load_project_links()?.links,
+            timeline,
+        })
+    }
+}
+
+/// Renders the resume in the given format
+pub fn export(resume: &Resume, format: ExportFormat, options: &ExportOptions) -> Result<String, ContentError> {
+    match format {
+        ExportFormat::JsonResume => json_resume::to_json(resume),
+        ExportFormat::Html => Ok(html::to_html(resume)),
+        ExportFormat::Text => Ok(document::to_text(resume, options.width)),
+        ExportFormat::Markdown => Ok(document::to_markdown(resume, options.width)),
+        ExportFormat::Latex => latex::to_latex(resume, options.template.as_deref()),
+        ExportFormat::Typst => typst::to_typst(resume, options.template.as_deref()),
+    }
+}
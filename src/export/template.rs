@@ -0,0 +1,63 @@
+use crate::ContentError;
+use std::fs;
+use std::path::Path;
+
+/// A template bundled into the binary, as `(name, source)`
+pub type BundledTemplate = (&'static str, &'static str);
+
+/// Returns the source of the requested template: a file path, a bundled template name, or the first bundled template
+pub fn load_template(bundled: &[BundledTemplate], requested: Option<&str>) -> Result<(String, String), ContentError> {
+    let Some(requested) = requested else {
+        let (name, source) = bundled[0];
+        return Ok((name.to_string(), source.to_string()));
+    };
+
+    if Path::new(requested).is_file() {
+        let source = fs::read_to_string(requested).map_err(|e| ContentError::from_io(requested, e))?;
+        return Ok((requested.to_string(), source));
+    }
+
+    bundled
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(requested))
+        .map(|(name, source)| (name.to_string(), source.to_string()))
+        .ok_or_else(|| {
+            let names: Vec<&str> = bundled.iter().map(|(name, _)| *name).collect();
+            ContentError::schema(
+                requested,
+                format!("not a template file or one of the bundled templates: {}", names.join(", ")),
+            )
+        })
+}
+
+/// Replaces every `{{placeholder}}` in the template with its value
+pub fn render_template(name: &str, source: &str, values: &[(&str, String)]) -> Result<String, ContentError> {
+    let mut output = String::with_capacity(source.len());
+    let mut rest = source;
+
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let placeholder = after
+            .find("}}")
+            .map(|end| &after[..end])
+            .filter(|key| !key.is_empty() && key.chars().all(|c| c.is_ascii_lowercase() || c == '_'));
+
+        let Some(key) = placeholder else {
+            output.push_str(&rest[..start + 2]);
+            rest = after;
+            continue;
+        };
+
+        let value = values.iter().find(|(k, _)| *k == key).map(|(_, v)| v).ok_or_else(|| {
+            let keys: Vec<&str> = values.iter().map(|(k, _)| *k).collect();
+            ContentError::schema(name, format!("unknown placeholder {{{{{}}}}}, expected one of: {}", key, keys.join(", ")))
+        })?;
+
+        output.push_str(&rest[..start]);
+        output.push_str(value);
+        rest = &after[key.len() + 2..];
+    }
+
+    output.push_str(rest);
+    Ok(output)
+}
@@ -0,0 +1,141 @@
+use super::template::{load_template, render_template, BundledTemplate};
+use super::Resume;
+use crate::tui::markdown::markdown_parser;
+use crate::tui::models::format_duration;
+use crate::{ContentError, YearMonth};
+use pulldown_cmark::{Event, Tag};
+
+/// Typst templates bundled into the binary; the first one is the default
+pub const TEMPLATES: &[BundledTemplate] = &[
+    ("classic", include_str!("../static/templates/classic.typ")),
+    ("compact", include_str!("../static/templates/compact.typ")),
+];
+
+/// Renders the resume as Typst source through a bundled template or a template file
+pub fn to_typst(resume: &Resume, template: Option<&str>) -> Result<String, ContentError> {
+    let (name, source) = load_template(TEMPLATES, template)?;
+    let today = YearMonth::today();
+
+    let contact: Vec<String> = resume
+        .contact_fields
+        .iter()
+        .map(|field| match &field.url {
+            Some(url) => format!("#resume-contact({}, {}, url: {})", string(&field.label), string(&field.value), string(url)),
+            None => format!("#resume-contact({}, {})", string(&field.label), string(&field.value)),
+        })
+        .collect();
+
+    let mut skills = Vec::new();
+    for category in &resume.skills.categories {
+        skills.push(format!("#skill-category({})", string(&category.name)));
+        for skill in &category.skills {
+            skills.push(format!("#skill({}, {})", string(&skill.name), skill.level.min(100)));
+        }
+    }
+
+    let project_links: Vec<String> = resume
+        .project_links
+        .iter()
+        .map(|link| format!("#project-link({}, {})", string(&link.text), string(&link.url)))
+        .collect();
+
+    let timeline: Vec<String> = resume
+        .timeline
+        .iter()
+        .map(|event| {
+            let dates = match (event.date_range_label(), event.duration_months(today)) {
+                (Some(range), Some(months)) => format!("{} ({})", range, format_duration(months)),
+                _ => String::new(),
+            };
+            let highlights: Vec<String> = event.highlights.iter().flatten().map(|h| string(h.trim())).collect();
+            let technologies: Vec<String> = event.technologies.iter().flatten().map(|t| string(t)).collect();
+
+            format!(
+                "#event(year: {}, title: {}, organization: {}, kind: {}, dates: {}, description: {}, highlights: {}, technologies: {})",
+                string(&event.year.to_string()),
+                string(&event.title),
+                string(&event.organization),
+                string(event.event_type.label()),
+                string(&dates),
+                string(&event.description),
+                array(&highlights),
+                array(&technologies)
+            )
+        })
+        .collect();
+
+    render_template(
+        &name,
+        &source,
+        &[
+            ("name", escape(resume.name.as_deref().unwrap_or("Resume"))),
+            ("contact", contact.join("\n")),
+            ("about", markdown_to_typst(&resume.about)),
+            ("skills", skills.join("\n")),
+            ("projects", markdown_to_typst(&resume.projects)),
+            ("project_links", project_links.join("\n")),
+            ("timeline", timeline.join("\n")),
+        ],
+    )
+}
+
+/// Renders markdown as Typst markup using the same parser as the TUI
+pub fn markdown_to_typst(content: &str) -> String {
+    let mut typst = String::new();
+
+    for event in markdown_parser(content) {
+        match event {
+            Event::Start(Tag::Heading(level, ..)) => {
+                typst.push_str(&"=".repeat(level as usize));
+                typst.push(' ');
+            }
+            Event::End(Tag::Heading(..) | Tag::Paragraph) => typst.push_str("\n\n"),
+            Event::End(Tag::List(_)) => typst.push('\n'),
+            Event::Start(Tag::Item) => typst.push_str("- "),
+            Event::End(Tag::Item) => typst.push('\n'),
+            Event::Start(Tag::Strong) => typst.push_str("#strong["),
+            Event::Start(Tag::Emphasis) => typst.push_str("#emph["),
+            Event::Start(Tag::Strikethrough) => typst.push_str("#strike["),
+            Event::Start(Tag::Link(_, url, _)) => typst.push_str(&format!("#link({})[", string(&url))),
+            Event::End(Tag::Strong | Tag::Emphasis | Tag::Strikethrough | Tag::Link(..)) => typst.push(']'),
+            Event::Text(text) => {
+                if typst.ends_with([']', ')']) && text.starts_with(['.', '(']) {
+                    typst.push('\\');
+                }
+                typst.push_str(&escape(&text));
+            }
+            Event::Code(text) => typst.push_str(&format!("#raw({})", string(&text))),
+            Event::SoftBreak => typst.push(' '),
+            Event::HardBreak => typst.push_str(" \\\n"),
+            _ => {}
+        }
+    }
+
+    typst.trim_end().to_string()
+}
+
+/// Escapes text for Typst markup
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if "\\*_#$@<>[]`~=-+/'\"".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+fn string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn array(items: &[String]) -> String {
+    match items.len() {
+        0 => String::from("()"),
+        1 => format!("({},)", items[0]),
+        _ => format!("({})", items.join(", ")),
+    }
+}
@@ -1,69 +1,88 @@
-use std::fs;
-use std::path::Path;
 use std::error::Error;
 
+pub mod contact;
+pub mod content;
+pub mod export;
+pub mod sections;
+pub mod text;
 pub mod tui;
 
+pub use contact::{parse_contact_fields, ContactField};
+pub use sections::{load_sections, Section, SectionItem};
+
+pub use content::{ContentError, ContentSource, read_content_file, read_json_file, set_content_source};
+use tui::models::SkillsData;
+pub use tui::models::{TimelineDate, TimelineEvent, TimelineType, YearMonth};
+
 /// Returns a greeting message
 pub fn greeting() -> String {
     String::from("Hello Warp, I am David Parker.")
 }
 
 /// Load content from markdown files
-pub fn load_content(filename: &str) -> String {
-    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
-        .join("src")
-        .join("static")
-        .join("content")
-        .join(filename);
-
-    match fs::read_to_string(&path) {
-        Ok(content) => content,
-        Err(_) => format!("Error: Failed to load content from '{}'", filename),
-    }
+pub fn load_content(filename: &str) -> Result<String, ContentError> {
+    read_content_file(filename)
 }
 
 /// Returns the about content
-pub fn about() -> String {
+pub fn about() -> Result<String, ContentError> {
     load_content("about.md")
 }
 
 /// Returns the skills content
-pub fn skills() -> String {
+pub fn skills() -> Result<String, ContentError> {
     load_content("skills.md")
 }
 
 /// Returns the projects content
-pub fn projects() -> String {
+pub fn projects() -> Result<String, ContentError> {
     load_content("projects.md")
 }
 
-
 /// Returns the welcome content
-pub fn welcome() -> String {
+pub fn welcome() -> Result<String, ContentError> {
     load_content("welcome.md")
 }
 
 /// Returns the timeline content
-pub fn timeline() -> String {
+pub fn timeline() -> Result<String, ContentError> {
     load_content("timeline.md")
 }
 
 /// Returns the contact content
-pub fn contact() -> String {
+pub fn contact() -> Result<String, ContentError> {
     load_content("contact.md")
 }
 
+/// Returns the labelled contact details from the contact content
+pub fn load_contact_fields() -> Result<Vec<ContactField>, ContentError> {
+    Ok(parse_contact_fields(&contact()?))
+}
+
 /// Loads timeline data from JSON file
-pub fn load_timeline_data() -> Result<Vec<TimelineEvent>, Box<dyn Error>> {
-    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
-        .join("src")
-        .join("static")
-        .join("content")
-        .join("timeline.json");
-    
-    let content = fs::read_to_string(&path)?;
-    let timeline_events: Vec<TimelineEvent> = serde_json::from_str(&content)?;
+pub fn load_timeline_data() -> Result<Vec<TimelineEvent>, ContentError> {
+    let timeline_events: Vec<TimelineEvent> = read_json_file("timeline.json")?;
+
+    for (i, event) in timeline_events.iter().enumerate() {
+        if event.year == 0 {
+            return Err(ContentError::schema("timeline.json", format!("event {} has no year", i + 1)));
+        }
+        if event.title.trim().is_empty() {
+            return Err(ContentError::schema("timeline.json", format!("event {} has an empty title", i + 1)));
+        }
+        if event.start == Some(TimelineDate::Present) {
+            return Err(ContentError::schema("timeline.json", format!("event {} cannot start at \"present\"", i + 1)));
+        }
+        if event.end.is_some() && event.start.is_none() {
+            return Err(ContentError::schema("timeline.json", format!("event {} has an end date but no start date", i + 1)));
+        }
+        if let (Some(TimelineDate::Month(start)), Some(TimelineDate::Month(end))) = (event.start, event.end) {
+            if end < start {
+                return Err(ContentError::schema("timeline.json", format!("event {} ends before it starts", i + 1)));
+            }
+        }
+    }
+
     Ok(timeline_events)
 }
 
@@ -81,39 +100,42 @@ pub struct ProjectLinks {
 }
 
 /// Loads project links data from JSON file
-pub fn load_project_links() -> Result<ProjectLinks, Box<dyn Error>> {
-    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
-        .join("src")
-        .join("static")
-        .join("content")
-        .join("projects.json");
-    
-    let content = fs::read_to_string(&path)?;
-    let project_links: ProjectLinks = serde_json::from_str(&content)?;
+pub fn load_project_links() -> Result<ProjectLinks, ContentError> {
+    let project_links: ProjectLinks = read_json_file("projects.json")?;
+
+    if let Some(link) = project_links.links.iter().find(|link| link.url.trim().is_empty()) {
+        return Err(ContentError::schema("projects.json", format!("link '{}' has an empty url", link.text)));
+    }
+
     Ok(project_links)
 }
 
-/// Represents a timeline event
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
-pub struct TimelineEvent {
-    pub year: u32,
-    pub title: String,
-    pub organization: String,
-    pub description: String,
-    pub highlights: Vec<String>,
-    pub technologies: Vec<String>,
+/// Loads skills data from JSON file
+pub fn load_skills_data() -> Result<SkillsData, ContentError> {
+    let skills_data: SkillsData = read_json_file("skills.json")?;
+
+    for category in &skills_data.categories {
+        if let Some(skill) = category.skills.iter().find(|skill| skill.level > 100) {
+            return Err(ContentError::schema(
+                "skills.json",
+                format!("skill '{}' has level {}, expected 0-100", skill.name, skill.level),
+            ));
+        }
+    }
+
+    Ok(skills_data)
 }
 
 /// Runs the interactive TUI application
 #[cfg(not(test))]
-pub fn run_tui() -> Result<(), Box<dyn Error>> {
-    tui::run()?;
+pub fn run_tui(options: tui::TuiOptions) -> Result<(), Box<dyn Error>> {
+    tui::run(options)?;
     Ok(())
 }
 
 /// Test version of run_tui that doesn't actually launch the TUI
 #[cfg(test)]
-pub fn run_tui() -> Result<(), Box<dyn Error>> {
+pub fn run_tui(_options: tui::TuiOptions) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
@@ -128,45 +150,50 @@ mod tests {
 
     #[test]
     fn test_about_content() {
-        let about_content = about();
-        assert!(about_content.contains("About David Parker"));
+        let about_content = about().unwrap();
+        assert!(about_content.contains("David Parker"));
         assert!(about_content.contains("Warp team"));
     }
 
     #[test]
     fn test_skills_content() {
-        let skills_content = skills();
+        let skills_content = skills().unwrap();
         assert!(!skills_content.is_empty());
-        assert!(!skills_content.contains("Error: Failed to load content from 'skills.md'"));
     }
 
     #[test]
     fn test_projects_content() {
-        let projects_content = projects();
+        let projects_content = projects().unwrap();
         assert!(!projects_content.is_empty());
-        assert!(!projects_content.contains("Error: Failed to load content from 'projects.md'"));
     }
 
 
     #[test]
     fn test_welcome_content() {
-        let welcome_content = welcome();
+        let welcome_content = welcome().unwrap();
         assert!(!welcome_content.is_empty());
-        assert!(!welcome_content.contains("Error: Failed to load content from 'welcome.md'"));
     }
 
     #[test]
     fn test_timeline_content() {
-        let timeline_content = timeline();
+        let timeline_content = timeline().unwrap();
         assert!(!timeline_content.is_empty());
-        assert!(!timeline_content.contains("Error: Failed to load content from 'timeline.md'"));
     }
     
     #[test]
     fn test_contact_content() {
-        let contact_content = contact();
+        let contact_content = contact().unwrap();
         assert!(!contact_content.is_empty());
-        assert!(!contact_content.contains("Error: Failed to load content from 'contact.md'"));
+    }
+
+    #[test]
+    fn test_load_contact_fields() {
+        let fields = load_contact_fields().unwrap();
+        let email = fields.iter().find(|field| field.is_named("email")).unwrap();
+        assert!(email.value.contains('@'));
+
+        let github = fields.iter().find(|field| field.is_named("GitHub")).unwrap();
+        assert!(github.url.as_deref().unwrap().starts_with("https://"));
     }
 
     #[test]
@@ -182,17 +209,40 @@ mod tests {
         assert!(!first_event.title.is_empty());
         assert!(!first_event.organization.is_empty());
         assert!(!first_event.description.is_empty());
+        assert_eq!(first_event.event_type, TimelineType::Education);
+        assert!(timeline_events.iter().any(|event| event.event_type == TimelineType::Career));
     }
 
     #[test]
     fn test_load_content_error_handling() {
-        let content = load_content("nonexistent_file.md");
-        assert!(content.contains("Error: Failed to load content from 'nonexistent_file.md'"));
+        let result = load_content("nonexistent_file.md");
+        assert!(matches!(result, Err(ContentError::Missing { ref file }) if file == "nonexistent_file.md"));
+    }
+
+    #[test]
+    fn test_load_project_links() {
+        let project_links = load_project_links().unwrap();
+        assert!(!project_links.links.is_empty());
+    }
+
+    #[test]
+    fn test_load_skills_data() {
+        let skills_data = load_skills_data().unwrap();
+        assert!(!skills_data.categories.is_empty());
     }
     
+    #[test]
+    fn test_tui_options_report_every_warning() {
+        let mut options = tui::TuiOptions::default();
+        assert_eq!(options.warning_status(), None);
+
+        options.warnings = vec![String::from("invalid theme 'nord'"), String::from("invalid keymap: unknown key 'hyper'")];
+        assert_eq!(options.warning_status().unwrap(), "invalid theme 'nord'; invalid keymap: unknown key 'hyper'");
+    }
+
     #[test]
     fn test_run_tui() {
-        let result = run_tui();
+        let result = run_tui(tui::TuiOptions::default());
         assert!(result.is_ok());
     }
 }
@@ -1,11 +1,41 @@
+use clap::builder::TypedValueParser;
 use clap::Parser;
-use hire_david_parker::{about, run_tui};
+use hire_david_parker::content::xdg_content_dir;
+use hire_david_parker::export::{export, json_resume, ExportFormat, ExportOptions, Resume};
+use hire_david_parker::text::{
+    markdown_to_text, project_links_to_text, skills_to_text, timeline_to_text,
+};
+use hire_david_parker::tui::models::{SkillsData, TimelineFilter, TimelineType};
+use hire_david_parker::tui::theme::ColorDepth;
+#[cfg(not(test))]
+use hire_david_parker::tui::TuiOptions;
+use hire_david_parker::{
+    about, contact, load_contact_fields, load_project_links, load_skills_data, load_timeline_data, projects,
+    set_content_source, ContentSource,
+};
+#[cfg(not(test))]
+use hire_david_parker::run_tui;
 use std::error::Error;
+use std::io::IsTerminal;
+use std::fs;
+use std::path::PathBuf;
 
 #[derive(Parser, Debug, PartialEq)]
 #[command(name = "hire-david-parker")]
 #[command(about = "David Parker's interactive resume for Warp")]
 struct Cli {
+    /// Directory to load resume content from instead of the embedded content
+    #[arg(long, global = true, value_name = "DIR")]
+    content_dir: Option<PathBuf>,
+
+    /// Command that opens links from the TUI, e.g. "firefox" or "wslview %s"; overrides $BROWSER
+    #[arg(long, global = true, value_name = "COMMAND")]
+    browser: Option<String>,
+
+    /// Color theme for the TUI (dark, light, solarized, high-contrast), a user theme name, or a .toml theme file
+    #[arg(long, global = true, value_name = "NAME|FILE")]
+    theme: Option<String>,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -17,27 +47,219 @@ enum Commands {
 
     /// Display information about me
     About,
+
+    /// Display skills with their levels
+    Skills {
+        /// Only show the named skill category
+        #[arg(long)]
+        category: Option<String>,
+    },
+
+    /// Display projects and their links
+    Projects {
+        /// Only print the project links
+        #[arg(long)]
+        links: bool,
+    },
+
+    /// Display the career timeline
+    Timeline {
+        /// Only show events during the given year
+        #[arg(long)]
+        year: Option<u16>,
+
+        /// Only show events of the given type (career, education, certification, project)
+        #[arg(long = "type", value_name = "TYPE")]
+        event_type: Option<TimelineType>,
+
+        /// Only show events that used the given technology
+        #[arg(long, value_name = "TECHNOLOGY")]
+        tech: Option<String>,
+    },
+
+    /// Display contact information
+    Contact {
+        /// Only print the value of the given field, e.g. email or github
+        #[arg(long)]
+        field: Option<String>,
+    },
+
+    /// Export the whole resume to another format
+    Export {
+        /// Format to export (json-resume, html, txt, md, latex, typst)
+        #[arg(long, default_value = "json-resume")]
+        format: ExportFormat,
+
+        /// Column to wrap txt and md output at
+        #[arg(long, default_value_t = ExportOptions::DEFAULT_WIDTH, value_parser = clap::value_parser!(u16).range(20..).map(usize::from))]
+        width: usize,
+
+        /// Bundled template (classic, compact) or template file for latex and typst output
+        #[arg(long, value_name = "NAME|FILE")]
+        template: Option<String>,
+
+        /// Write the export to a file, or into a directory when the path ends with a slash or is a directory
+        #[arg(long, short, visible_alias = "out", value_name = "PATH")]
+        output: Option<PathBuf>,
+    },
+
+    /// Build a content directory from a JSON Resume document
+    Import {
+        /// The resume.json file to import
+        #[arg(value_name = "RESUME_JSON")]
+        file: PathBuf,
+
+        /// Directory to write the content to, defaults to the XDG content directory
+        #[arg(long, short, value_name = "DIR")]
+        output: Option<PathBuf>,
+
+        /// Overwrite the content files of a directory that is not empty
+        #[arg(long)]
+        force: bool,
+    },
 }
 
 /// Process CLI arguments and return the resulting output message
 pub fn process_args(args: &[String]) -> Result<String, Box<dyn Error>> {
     let cli = Cli::parse_from(args);
+    set_content_source(ContentSource::resolve(cli.content_dir));
+    // NO_COLOR and TERM=dumb detect as monochrome, which prints plain text like a pipe does
+    let depth = if std::io::stdout().is_terminal() { ColorDepth::detect() } else { ColorDepth::Monochrome };
 
     match cli.command {
         Some(Commands::Run) => {
             #[cfg(not(test))]
-            run_tui()?;
+            run_tui(TuiOptions::resolve(cli.browser, cli.theme)?)?;
             Ok(String::new())
         }
-        Some(Commands::About) => Ok(about()),
+        Some(Commands::About) => Ok(markdown_to_text(&about()?, depth)),
+        Some(Commands::Skills { category }) => skills_output(category.as_deref(), depth),
+        Some(Commands::Projects { links }) => projects_output(links, depth),
+        Some(Commands::Timeline { year, event_type, tech }) => timeline_output(year, event_type, tech, depth),
+        Some(Commands::Contact { field }) => contact_output(field.as_deref(), depth),
+        Some(Commands::Export { format, width, template, output }) => {
+            export_output(format, ExportOptions { width, template }, output)
+        }
+        Some(Commands::Import { file, output, force }) => import_output(file, output, force),
         None => {
             #[cfg(not(test))]
-            run_tui()?;
+            run_tui(TuiOptions::resolve(cli.browser, cli.theme)?)?;
             Ok(String::new())
         }
     }
 }
 
+fn skills_output(category: Option<&str>, depth: ColorDepth) -> Result<String, Box<dyn Error>> {
+    let mut skills_data = load_skills_data()?;
+
+    if let Some(name) = category {
+        let available: Vec<String> = skills_data.categories.iter().map(|c| c.name.clone()).collect();
+        skills_data = SkillsData {
+            categories: skills_data
+                .categories
+                .into_iter()
+                .filter(|c| c.name.eq_ignore_ascii_case(name))
+                .collect(),
+        };
+
+        if skills_data.categories.is_empty() {
+            return Err(format!("unknown skill category '{}', expected one of: {}", name, available.join(", ")).into());
+        }
+    }
+
+    Ok(skills_to_text(&skills_data, depth))
+}
+
+fn projects_output(links_only: bool, depth: ColorDepth) -> Result<String, Box<dyn Error>> {
+    let links = project_links_to_text(&load_project_links()?.links, depth);
+    if links_only {
+        return Ok(links);
+    }
+
+    Ok(format!("{}\n\n{}", markdown_to_text(&projects()?, depth), links))
+}
+
+fn timeline_output(
+    year: Option<u16>,
+    event_type: Option<TimelineType>,
+    tech: Option<String>,
+    depth: ColorDepth,
+) -> Result<String, Box<dyn Error>> {
+    let mut events = load_timeline_data()?;
+    events.sort_by_key(|event| (event.year, event.start));
+
+    let filters: Vec<TimelineFilter> = [
+        year.map(|year| TimelineFilter::YearRange(year, year)),
+        event_type.map(TimelineFilter::Type),
+        tech.map(TimelineFilter::Technology),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let matching: Vec<_> = events
+        .iter()
+        .filter(|event| filters.iter().all(|filter| filter.matches(event)))
+        .collect();
+
+    Ok(timeline_to_text(&matching, depth))
+}
+
+fn contact_output(field: Option<&str>, depth: ColorDepth) -> Result<String, Box<dyn Error>> {
+    let Some(name) = field else {
+        return Ok(markdown_to_text(&contact()?, depth));
+    };
+
+    let fields = load_contact_fields()?;
+    fields
+        .iter()
+        .find(|f| f.is_named(name))
+        .map(|f| f.value.clone())
+        .ok_or_else(|| {
+            let available: Vec<&str> = fields.iter().map(|f| f.label.as_str()).collect();
+            format!("unknown contact field '{}', expected one of: {}", name, available.join(", ")).into()
+        })
+}
+
+fn export_output(format: ExportFormat, options: ExportOptions, output: Option<PathBuf>) -> Result<String, Box<dyn Error>> {
+    let exported = export(&Resume::load()?, format, &options)?;
+
+    match output {
+        Some(path) => {
+            let path = export_path(path, format)?;
+            fs::write(&path, exported)?;
+            Ok(format!("Exported {} to {}", format.name(), path.display()))
+        }
+        None => Ok(exported),
+    }
+}
+
+fn export_path(path: PathBuf, format: ExportFormat) -> std::io::Result<PathBuf> {
+    let is_dir_path = path.to_string_lossy().ends_with(['/', std::path::MAIN_SEPARATOR]);
+    if !path.is_dir() && !is_dir_path {
+        return Ok(path);
+    }
+
+    fs::create_dir_all(&path)?;
+    Ok(path.join(format.file_name()))
+}
+
+fn import_output(file: PathBuf, output: Option<PathBuf>, force: bool) -> Result<String, Box<dyn Error>> {
+    let dir = output
+        .or_else(xdg_content_dir)
+        .ok_or("no output directory given and the XDG data directory could not be determined")?;
+    if !force && fs::read_dir(&dir).is_ok_and(|mut entries| entries.next().is_some()) {
+        return Err(format!("{} is not empty, pass --force to overwrite its content files", dir.display()).into());
+    }
+    let imported = json_resume::import(&fs::read_to_string(&file)?, &dir)?;
+
+    let mut result = format!("Imported {} into {} ({} files)", file.display(), dir.display(), imported.written.len());
+    for skipped in &imported.skipped {
+        result.push_str(&format!("\nSkipped {}", skipped));
+    }
+    Ok(result)
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
     let args: Vec<String> = std::env::args().collect();
     let result = process_args(&args)?;
@@ -52,6 +274,17 @@ mod tests {
     use super::*;
     use std::error::Error;
 
+    /// Runs the CLI against an empty content directory, so every file comes from the embedded content
+    /// whatever `HIRE_DAVID_PARKER_CONTENT_DIR` or the XDG content directory hold on this machine
+    fn run_cli(args: &[&str]) -> Result<String, Box<dyn Error>> {
+        let dir = std::env::temp_dir().join(format!("hdp-cli-embedded-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+
+        let mut cli_args = vec![String::from("app"), String::from("--content-dir"), dir.display().to_string()];
+        cli_args.extend(args.iter().map(|arg| arg.to_string()));
+        process_args(&cli_args)
+    }
+
     #[test]
     fn test_cli_parser() {
         let cli = Cli::parse_from(vec!["app", "run"]);
@@ -62,12 +295,22 @@ mod tests {
 
         let cli = Cli::parse_from(vec!["app"]);
         assert_eq!(cli.command, None);
+        assert_eq!(cli.content_dir, None);
+    }
+
+    #[test]
+    fn test_cli_content_dir_flag() {
+        let cli = Cli::parse_from(vec!["app", "--content-dir", "/tmp/resume", "about"]);
+        assert_eq!(cli.content_dir, Some(PathBuf::from("/tmp/resume")));
+        assert_eq!(cli.command, Some(Commands::About));
+
+        let cli = Cli::parse_from(vec!["app", "about", "--content-dir", "/tmp/resume"]);
+        assert_eq!(cli.content_dir, Some(PathBuf::from("/tmp/resume")));
     }
 
     #[test]
     fn test_process_args_about() -> Result<(), Box<dyn Error>> {
-        let args = vec![String::from("app"), String::from("about")];
-        let result = process_args(&args)?;
+        let result = run_cli(&["about"])?;
 
         assert!(!result.is_empty());
         assert!(result.contains("About David Parker") || result.contains("Warp team"));
@@ -75,10 +318,185 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_cli_section_commands() {
+        let cli = Cli::parse_from(vec!["app", "skills", "--category", "Languages"]);
+        assert_eq!(cli.command, Some(Commands::Skills { category: Some(String::from("Languages")) }));
+
+        let cli = Cli::parse_from(vec!["app", "projects", "--links"]);
+        assert_eq!(cli.command, Some(Commands::Projects { links: true }));
+
+        let cli = Cli::parse_from(vec!["app", "timeline", "--year", "2015", "--type", "career", "--tech", "Rust"]);
+        assert_eq!(
+            cli.command,
+            Some(Commands::Timeline {
+                year: Some(2015),
+                event_type: Some(TimelineType::Career),
+                tech: Some(String::from("Rust")),
+            })
+        );
+
+        let cli = Cli::parse_from(vec!["app", "contact", "--field", "email"]);
+        assert_eq!(cli.command, Some(Commands::Contact { field: Some(String::from("email")) }));
+
+        assert!(Cli::try_parse_from(vec!["app", "timeline", "--type", "hobby"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_export_and_import_commands() {
+        let cli = Cli::parse_from(vec!["app", "export"]);
+        assert_eq!(cli.command, Some(Commands::Export { format: ExportFormat::JsonResume, width: 80, template: None, output: None }));
+
+        let cli = Cli::parse_from(vec!["app", "export", "--format", "json-resume", "-o", "resume.json"]);
+        assert_eq!(
+            cli.command,
+            Some(Commands::Export {
+                format: ExportFormat::JsonResume,
+                width: 80,
+                template: None,
+                output: Some(PathBuf::from("resume.json")),
+            })
+        );
+
+        let cli = Cli::parse_from(vec!["app", "export", "--format", "txt", "--width", "60"]);
+        assert_eq!(
+            cli.command,
+            Some(Commands::Export { format: ExportFormat::Text, width: 60, template: None, output: None })
+        );
+
+        let cli = Cli::parse_from(vec!["app", "export", "--format", "typst", "--template", "compact"]);
+        assert_eq!(
+            cli.command,
+            Some(Commands::Export {
+                format: ExportFormat::Typst,
+                width: 80,
+                template: Some(String::from("compact")),
+                output: None,
+            })
+        );
+        assert!(Cli::try_parse_from(vec!["app", "export", "--width", "5"]).is_err());
+
+        let cli = Cli::parse_from(vec!["app", "import", "resume.json", "--output", "/tmp/resume"]);
+        assert_eq!(
+            cli.command,
+            Some(Commands::Import { file: PathBuf::from("resume.json"), output: Some(PathBuf::from("/tmp/resume")), force: false })
+        );
+
+        let cli = Cli::parse_from(vec!["app", "import", "resume.json", "--force"]);
+        assert_eq!(cli.command, Some(Commands::Import { file: PathBuf::from("resume.json"), output: None, force: true }));
+
+        assert!(Cli::try_parse_from(vec!["app", "export", "--format", "pdf"]).is_err());
+    }
+
+    #[test]
+    fn test_export_path() -> Result<(), Box<dyn Error>> {
+        let dir = std::env::temp_dir().join(format!("hdp-export-path-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let file = dir.join("resume.html");
+        assert_eq!(export_path(file.clone(), ExportFormat::Html)?, file);
+
+        let with_slash = PathBuf::from(format!("{}/", dir.display()));
+        assert_eq!(export_path(with_slash, ExportFormat::Html)?, dir.join("index.html"));
+        assert_eq!(export_path(dir.clone(), ExportFormat::JsonResume)?, dir.join("resume.json"));
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_output_refuses_non_empty_directories() -> Result<(), Box<dyn Error>> {
+        let dir = std::env::temp_dir().join(format!("hdp-import-output-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir)?;
+        let file = dir.join("resume.json");
+        fs::write(&file, r#"{ "work": [{ "name": "Org", "position": "Role" }] }"#)?;
+
+        let content = dir.join("content");
+        fs::create_dir_all(&content)?;
+        fs::write(content.join("about.md"), "My own about")?;
+
+        let error = import_output(file.clone(), Some(content.clone()), false).unwrap_err();
+        assert!(error.to_string().contains("--force"));
+        assert_eq!(fs::read_to_string(content.join("about.md"))?, "My own about");
+
+        let result = import_output(file, Some(content.clone()), true)?;
+        assert!(result.contains("Skipped 'Role' (Org) has no date"));
+        assert_ne!(fs::read_to_string(content.join("about.md"))?, "My own about");
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_args_export() -> Result<(), Box<dyn Error>> {
+        let result = run_cli(&["export"])?;
+        let document: serde_json::Value = serde_json::from_str(&result)?;
+
+        assert_eq!(document["basics"]["name"], "David Parker");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_args_skills() -> Result<(), Box<dyn Error>> {
+        let result = run_cli(&["skills"])?;
+        assert!(result.contains("Rust"));
+        assert!(result.contains("█") && result.contains("%"));
+
+        let result = run_cli(&["skills", "--category", "skills"])?;
+        assert!(result.starts_with("Skills"));
+
+        let error = run_cli(&["skills", "--category", "Cooking"]);
+        assert!(error.unwrap_err().to_string().contains("Cooking"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_args_projects() -> Result<(), Box<dyn Error>> {
+        let links = run_cli(&["projects", "--links"])?;
+        assert!(links.contains("https://"));
+
+        let result = run_cli(&["projects"])?;
+        assert!(result.ends_with(&links));
+        assert!(result.len() > links.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_args_timeline() -> Result<(), Box<dyn Error>> {
+        let all = run_cli(&["timeline"])?;
+        let education = run_cli(&["timeline", "--type", "education"])?;
+
+        assert!(all.contains("[Career]"));
+        assert!(education.contains("[Education]"));
+        assert!(!education.contains("[Career]"));
+
+        let none = run_cli(&["timeline", "--year", "1900"])?;
+        assert!(none.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_args_contact() -> Result<(), Box<dyn Error>> {
+        let email = run_cli(&["contact", "--field", "email"])?;
+        assert_eq!(email, "david@redkey.io");
+
+        let result = run_cli(&["contact"])?;
+        assert!(result.contains("david@redkey.io"));
+
+        let error = run_cli(&["contact", "--field", "fax"]);
+        assert!(error.unwrap_err().to_string().contains("Email"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_process_args_run() -> Result<(), Box<dyn Error>> {
-        let args = vec![String::from("app"), String::from("run")];
-        let result = process_args(&args)?;
+        let result = run_cli(&["run"])?;
 
         assert!(result.is_empty());
 
@@ -87,8 +505,7 @@ mod tests {
 
     #[test]
     fn test_process_args_no_command() -> Result<(), Box<dyn Error>> {
-        let args = vec![String::from("app")];
-        let result = process_args(&args)?;
+        let result = run_cli(&[])?;
 
         assert!(result.is_empty());
 
@@ -114,6 +531,9 @@ mod tests {
     #[test]
     fn test_cli_debug() {
         let cli = Cli {
+            content_dir: None,
+            browser: None,
+            theme: None,
             command: Some(Commands::Run),
         };
         let debug_str = format!("{:?}", cli);
@@ -125,12 +545,21 @@ mod tests {
     #[test]
     fn test_cli_eq() {
         let cli1 = Cli {
+            content_dir: None,
+            browser: None,
+            theme: None,
             command: Some(Commands::Run),
         };
         let cli2 = Cli {
+            content_dir: None,
+            browser: None,
+            theme: None,
             command: Some(Commands::Run),
         };
         let cli3 = Cli {
+            content_dir: None,
+            browser: None,
+            theme: None,
             command: Some(Commands::About),
         };
 
@@ -0,0 +1,157 @@
+use serde::Deserialize;
+
+use crate::content::{read_content_file, read_json_file, ContentError};
+
+/// The manifest files looked for in the content source, in order of preference
+pub const MANIFEST_FILES: [&str; 2] = ["sections.toml", "manifest.json"];
+
+/// A section that ships with the resume
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BuiltinSection {
+    About,
+    Skills,
+    Projects,
+    Timeline,
+    Contact,
+}
+
+/// A section declared by the manifest, in sidebar order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Section {
+    /// One of the sections that ship with the resume
+    Builtin(BuiltinSection),
+    /// A markdown file, optionally followed by the entries of a JSON data file
+    Custom { title: String, markdown: String, data: Option<String> },
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    sections: Vec<ManifestEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ManifestEntry {
+    builtin: Option<BuiltinSection>,
+    title: Option<String>,
+    markdown: Option<String>,
+    data: Option<String>,
+}
+
+/// An entry of a section's data file, such as a talk or an open source project
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SectionItem {
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub date: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Loads the sections of `sections.toml` or `manifest.json`, or None when the content has neither
+pub fn load_sections() -> Result<Option<Vec<Section>>, ContentError> {
+    for file in MANIFEST_FILES {
+        match read_content_file(file) {
+            Ok(source) => return parse_manifest(file, &source).map(Some),
+            Err(ContentError::Missing { .. }) => continue,
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(None)
+}
+
+/// Parses a manifest, as TOML when `file` ends in `.toml` and as JSON otherwise
+pub fn parse_manifest(file: &str, source: &str) -> Result<Vec<Section>, ContentError> {
+    let manifest: Manifest = if file.ends_with(".toml") {
+        toml::from_str(source).map_err(|e| ContentError::from_toml(file, source, e))?
+    } else {
+        serde_json::from_str(source).map_err(|e| ContentError::from_json(file, e))?
+    };
+
+    if manifest.sections.is_empty() {
+        return Err(ContentError::schema(file, "no sections are declared"));
+    }
+
+    let mut sections = Vec::new();
+    for (i, entry) in manifest.sections.into_iter().enumerate() {
+        let section = match entry {
+            ManifestEntry { builtin: Some(builtin), title: None, markdown: None, data: None } => Section::Builtin(builtin),
+            ManifestEntry { builtin: Some(_), .. } => {
+                return Err(ContentError::schema(file, format!("section {} is built in and cannot set a title, markdown or data", i + 1)));
+            }
+            ManifestEntry { title: Some(title), markdown: Some(markdown), data, .. } if !title.trim().is_empty() => {
+                Section::Custom { title, markdown, data }
+            }
+            ManifestEntry { markdown: Some(_), .. } => {
+                return Err(ContentError::schema(file, format!("section {} has no title", i + 1)));
+            }
+            ManifestEntry { .. } => {
+                return Err(ContentError::schema(file, format!("section {} needs a markdown file or a builtin section", i + 1)));
+            }
+        };
+
+        if matches!(section, Section::Builtin(_)) && sections.contains(&section) {
+            return Err(ContentError::schema(file, format!("section {} repeats a built-in section", i + 1)));
+        }
+        sections.push(section);
+    }
+
+    Ok(sections)
+}
+
+/// Loads the entries of a section's JSON data file
+pub fn load_section_items(file: &str) -> Result<Vec<SectionItem>, ContentError> {
+    let items: Vec<SectionItem> = read_json_file(file)?;
+
+    if let Some(i) = items.iter().position(|item| item.title.trim().is_empty()) {
+        return Err(ContentError::schema(file, format!("entry {} has an empty title", i + 1)));
+    }
+
+    Ok(items)
+}
+
+/// Renders data entries as markdown, one heading per entry
+pub fn section_items_markdown(items: &[SectionItem]) -> String {
+    let mut markdown = String::new();
+
+    for item in items {
+        markdown.push_str(&format!("### {}\n\n", item.title));
+
+        let byline: Vec<&str> = [item.subtitle.as_deref(), item.date.as_deref()].into_iter().flatten().collect();
+        if !byline.is_empty() {
+            markdown.push_str(&format!("*{}*\n\n", byline.join(" · ")));
+        }
+        if let Some(description) = &item.description {
+            markdown.push_str(&format!("{}\n\n", description));
+        }
+        if let Some(url) = &item.url {
+            markdown.push_str(&format!("[{}]({})\n\n", url, url));
+        }
+        if !item.tags.is_empty() {
+            markdown.push_str(&format!("**Tags:** {}\n\n", item.tags.join(", ")));
+        }
+    }
+
+    markdown
+}
+
+/// Loads a custom section's markdown followed by its data entries
+pub fn load_section_content(markdown: &str, data: Option<&str>) -> Result<String, ContentError> {
+    let mut content = read_content_file(markdown)?;
+
+    if let Some(data) = data {
+        let items = load_section_items(data)?;
+        if !content.is_empty() {
+            content = format!("{}\n\n", content.trim_end());
+        }
+        content.push_str(&section_items_markdown(&items));
+    }
+
+    Ok(content)
+}
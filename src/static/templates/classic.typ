@@ -0,0 +1,49 @@
+#set page(margin: 2cm)
+#set text(size: 11pt)
+#set par(justify: false)
+
+#let accent = rgb("#2a6f97")
+#show heading.where(level: 1): it => text(fill: accent, it)
+
+#let resume-contact(label, value, url: none) = [
+  *#label:* #if url == none { value } else { link(url, value) } \
+]
+#let skill-category(name) = heading(level: 2, name)
+#let skill(name, level) = grid(
+  columns: (5.5cm, 4cm, 1.5cm),
+  column-gutter: 0.8em,
+  name,
+  box(width: 100%, height: 0.6em, fill: luma(225), box(width: level * 1%, height: 100%, fill: accent)),
+  [#level%],
+)
+#let project-link(name, url) = [• #link(url, name) \ ]
+#let event(year: "", title: "", organization: "", kind: "", dates: "", description: "", highlights: (), technologies: ()) = [
+  #heading(level: 2)[#year #h(1em) #title]
+  #emph[#organization · #kind] #h(1fr) #dates
+
+  #description
+  #for highlight in highlights [
+    - #highlight
+  ]
+  #if technologies.len() > 0 [
+    #emph[Technologies: #technologies.join(", ")]
+  ]
+]
+
+#text(22pt, weight: "bold")[{{name}}]
+
+{{contact}}
+
+= About
+{{about}}
+
+= Skills
+{{skills}}
+
+= Projects
+{{projects}}
+
+{{project_links}}
+
+= Timeline
+{{timeline}}
@@ -0,0 +1,26 @@
+#set page(margin: 1.5cm)
+#set text(size: 10pt)
+
+#let resume-contact(label, value, url: none) = [#if url == none { value } else { link(url, value) } #h(1em)]
+#let skill-category(name) = [\ *#name:* ]
+#let skill(name, level) = [#name (#level%) #h(0.6em)]
+#let project-link(name, url) = [#link(url, name) #h(1em)]
+#let event(year: "", title: "", organization: "", kind: "", dates: "", description: "", highlights: (), technologies: ()) = [
+  *#year – #title*, #organization #h(1fr) #dates \
+  #description
+  #for highlight in highlights [
+    - #highlight
+  ]
+]
+
+#text(16pt, weight: "bold")[{{name}}] \
+{{contact}}
+
+== Skills
+{{skills}}
+
+== Experience
+{{timeline}}
+
+== Projects
+{{project_links}}
@@ -0,0 +1,265 @@
+use crate::tui::markdown::{parse_markdown, style_columns};
+use crate::tui::models::{format_duration, SkillsData, TimelineEvent, YearMonth};
+use crate::tui::theme::{ColorDepth, Theme};
+use crate::{ContactField, ProjectLink};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Text;
+use unicode_width::UnicodeWidthStr;
+
+const SKILL_BAR_WIDTH: usize = 10;
+
+/// Renders markdown content as terminal text, with ANSI styling unless `depth` is monochrome
+pub fn markdown_to_text(content: &str, depth: ColorDepth) -> String {
+    let (mut text, links) = parse_markdown(content);
+    let link_style = Theme::default().link_style();
+    for link in &links {
+        if let Some(line) = text.lines.get_mut(link.line) {
+            style_columns(line, link.start_column, link.end_column, link_style);
+        }
+    }
+    text_to_string(&text, depth)
+}
+
+/// Flattens styled text into a string, emitting ANSI escape codes with colors adapted to `depth`
+pub fn text_to_string(text: &Text, depth: ColorDepth) -> String {
+    let lines: Vec<String> = text
+        .lines
+        .iter()
+        .map(|line| {
+            line.spans
+                .iter()
+                .map(|span| paint(&span.content, line.style.patch(span.style), depth))
+                .collect::<String>()
+        })
+        .collect();
+
+    lines.join("\n").trim_end().to_string()
+}
+
+/// Draws a skill level as a text bar, e.g. `[█████░░░░░] 50%`
+pub fn skill_bar(level: u8) -> String {
+    let level = level.min(100);
+    let filled = (usize::from(level) * SKILL_BAR_WIDTH + 50) / 100;
+    format!(
+        "[{}{}] {}%",
+        "█".repeat(filled),
+        "░".repeat(SKILL_BAR_WIDTH - filled),
+        level
+    )
+}
+
+/// Renders every skill category with a level bar per skill
+pub fn skills_to_text(skills_data: &SkillsData, depth: ColorDepth) -> String {
+    let name_width = skills_data
+        .categories
+        .iter()
+        .flat_map(|category| category.skills.iter())
+        .map(|skill| skill.name.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let sections: Vec<String> = skills_data
+        .categories
+        .iter()
+        .map(|category| {
+            let mut lines = vec![paint(&category.name, Style::default().add_modifier(Modifier::BOLD), depth)];
+            for skill in &category.skills {
+                lines.push(format!(
+                    "  {:<width$}  {}",
+                    skill.name,
+                    paint(&skill_bar(skill.level), Style::default().fg(Theme::default().gauge), depth),
+                    width = name_width
+                ));
+            }
+            lines.join("\n")
+        })
+        .collect();
+
+    sections.join("\n\n")
+}
+
+/// Renders project links as `text - url` lines
+pub fn project_links_to_text(links: &[ProjectLink], depth: ColorDepth) -> String {
+    links
+        .iter()
+        .map(|link| {
+            format!(
+                "{} - {}",
+                paint(&link.text, Style::default().add_modifier(Modifier::BOLD), depth),
+                paint(&link.url, Theme::default().link_style(), depth)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders timeline events with their dates, highlights, and technologies
+pub fn timeline_to_text(events: &[&TimelineEvent], depth: ColorDepth) -> String {
+    let today = YearMonth::today();
+
+    events
+        .iter()
+        .map(|event| {
+            let mut lines = vec![format!(
+                "{}  {} | {}  {}",
+                paint(&event.year.to_string(), Style::default().add_modifier(Modifier::BOLD), depth),
+                paint(&event.title, Style::default().fg(Theme::default().highlight).add_modifier(Modifier::BOLD), depth),
+                event.organization,
+                paint(
+                    &format!("[{}]", event.event_type.label()),
+                    Style::default().fg(Theme::default().timeline_color(event.event_type)),
+                    depth
+                )
+            )];
+
+            if let (Some(range), Some(months)) = (event.date_range_label(), event.duration_months(today)) {
+                lines.push(format!("      {} ({})", range, format_duration(months)));
+            }
+
+            lines.push(format!("      {}", event.description));
+
+            for highlight in event.highlights.iter().flatten() {
+                lines.push(format!("      • {}", highlight.trim()));
+            }
+
+            if let Some(technologies) = event.technologies.as_ref().filter(|techs| !techs.is_empty()) {
+                lines.push(format!(
+                    "      {}",
+                    paint(&format!("Technologies: {}", technologies.join(", ")), Style::default().fg(Theme::default().gauge), depth)
+                ));
+            }
+
+            lines.join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Renders contact fields as `Label: value` lines
+pub fn contact_fields_to_text(fields: &[ContactField], depth: ColorDepth) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            format!(
+                "{} {}",
+                paint(&format!("{}:", field.label), Style::default().add_modifier(Modifier::BOLD), depth),
+                field.value
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Wraps lines longer than `width` columns at word boundaries, indenting continuations under list items.
+/// Headings and fenced code blocks are left as they are.
+pub fn wrap_text(text: &str, width: usize) -> String {
+    let mut in_code_block = false;
+    let mut lines = Vec::new();
+
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            lines.push(line.to_string());
+        } else if in_code_block || line.starts_with('#') || line.width() <= width {
+            lines.push(line.to_string());
+        } else {
+            lines.extend(wrap_line(line, width));
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    let content = line.trim_start();
+    let mut prefix_len = line.len() - content.len();
+    if let Some(marker) = ["• ", "- ", "* "].into_iter().find(|marker| content.starts_with(marker)) {
+        let item = &content[marker.len()..];
+        prefix_len += marker.len() + item.len() - item.trim_start().len();
+    }
+
+    let (prefix, body) = line.split_at(prefix_len);
+    let indent = " ".repeat(prefix.width());
+    let mut lines = Vec::new();
+    let mut current = prefix.to_string();
+    let mut current_width = prefix.width();
+    let mut line_is_empty = true;
+
+    for word in body.split_whitespace() {
+        if !line_is_empty && current_width + 1 + word.width() > width {
+            lines.push(current);
+            current = indent.clone();
+            current_width = indent.len();
+            line_is_empty = true;
+        }
+        if !line_is_empty {
+            current.push(' ');
+            current_width += 1;
+        }
+        current.push_str(word);
+        current_width += word.width();
+        line_is_empty = false;
+    }
+
+    lines.push(current);
+    lines
+}
+
+fn paint(content: &str, style: Style, depth: ColorDepth) -> String {
+    let codes = sgr_codes(style, depth);
+    if depth == ColorDepth::Monochrome || codes.is_empty() || content.is_empty() {
+        return content.to_string();
+    }
+
+    format!("\x1b[{}m{}\x1b[0m", codes.join(";"), content)
+}
+
+fn sgr_codes(style: Style, depth: ColorDepth) -> Vec<String> {
+    let mut codes = Vec::new();
+    let modifiers = [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::CROSSED_OUT, "9"),
+    ];
+
+    for (modifier, code) in modifiers {
+        if style.add_modifier.contains(modifier) {
+            codes.push(code.to_string());
+        }
+    }
+
+    if let Some(color) = style.fg.map(|color| depth.adapt(color)).and_then(foreground_code) {
+        codes.push(color);
+    }
+
+    codes
+}
+
+fn foreground_code(color: Color) -> Option<String> {
+    let code = match color {
+        Color::Reset => return None,
+        Color::Black => "30",
+        Color::Red => "31",
+        Color::Green => "32",
+        Color::Yellow => "33",
+        Color::Blue => "34",
+        Color::Magenta => "35",
+        Color::Cyan => "36",
+        Color::Gray => "37",
+        Color::DarkGray => "90",
+        Color::LightRed => "91",
+        Color::LightGreen => "92",
+        Color::LightYellow => "93",
+        Color::LightBlue => "94",
+        Color::LightMagenta => "95",
+        Color::LightCyan => "96",
+        Color::White => "97",
+        Color::Rgb(r, g, b) => return Some(format!("38;2;{};{};{}", r, g, b)),
+        Color::Indexed(i) => return Some(format!("38;5;{}", i)),
+    };
+
+    Some(code.to_string())
+}
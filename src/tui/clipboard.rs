@@ -0,0 +1,138 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::env;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+
+/// Environment variable naming a command that receives copied text on its standard input
+pub const CLIPBOARD_ENV: &str = "HIRE_DAVID_PARKER_CLIPBOARD";
+
+/// Returns the OSC 52 escape sequence that asks the terminal to put `text` on the clipboard
+pub fn osc52_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", STANDARD.encode(text))
+}
+
+/// Copies `text` to the system clipboard through the terminal
+pub fn copy(writer: &mut impl Write, text: &str) -> io::Result<()> {
+    writer.write_all(osc52_sequence(text).as_bytes())?;
+    writer.flush()
+}
+
+/// Returns true if the terminal is expected to honor OSC 52
+pub fn osc52_supported() -> bool {
+    osc52_supported_from(|name| env::var(name).ok())
+}
+
+/// Decides OSC 52 support from the environment variables returned by `var`
+///
+/// A configured clipboard command wins over the terminal, and over SSH OSC 52 is the only way to
+/// reach the local clipboard.
+pub fn osc52_supported_from(var: impl Fn(&str) -> Option<String>) -> bool {
+    if var(CLIPBOARD_ENV).is_some_and(|command| !command.trim().is_empty()) {
+        return false;
+    }
+
+    let term = var("TERM").unwrap_or_default();
+    if term.is_empty() || term == "dumb" {
+        return false;
+    }
+
+    var("SSH_CONNECTION").is_some()
+        || var("SSH_TTY").is_some()
+        || var("TERM_PROGRAM").is_some_and(|program| ["iTerm.app", "WezTerm", "ghostty", "tmux"].contains(&program.as_str()))
+        || ["xterm-kitty", "xterm-ghostty", "alacritty", "foot", "wezterm", "tmux"].iter().any(|prefix| term.starts_with(prefix))
+        || ["TMUX", "KITTY_WINDOW_ID", "WT_SESSION", "ALACRITTY_WINDOW_ID"].iter().any(|name| var(name).is_some())
+}
+
+/// Copies `text` with OSC 52 when `osc52` is set, falling back to the local `commands` if the
+/// terminal is not expected to support it or the sequence cannot be written
+///
+/// The commands run on a background thread, and the outcome, naming what did the copy, arrives on
+/// the returned receiver.
+pub fn copy_text(writer: &mut impl Write, text: &str, osc52: bool, commands: Vec<String>) -> mpsc::Receiver<Result<String, String>> {
+    let (sender, receiver) = mpsc::channel();
+
+    let osc52_error = if osc52 {
+        match copy(writer, text) {
+            Ok(()) => {
+                let _ = sender.send(Ok(String::from("the terminal")));
+                return receiver;
+            }
+            Err(e) => format!("OSC 52: {}", e),
+        }
+    } else {
+        String::from("the terminal does not support OSC 52")
+    };
+
+    let text = text.to_string();
+    thread::spawn(move || {
+        let result = copy_with_commands(&commands, &text).map_err(|error| format!("{}; {}", osc52_error, error));
+        let _ = sender.send(result);
+    });
+    receiver
+}
+
+/// Returns the local clipboard commands for the current environment
+pub fn local_commands() -> Vec<String> {
+    local_commands_from(|name| env::var(name).ok())
+}
+
+/// Picks local clipboard commands from the environment variables returned by `var`, none over SSH
+pub fn local_commands_from(var: impl Fn(&str) -> Option<String>) -> Vec<String> {
+    if let Some(command) = var(CLIPBOARD_ENV).filter(|command| !command.trim().is_empty()) {
+        return vec![command];
+    }
+    if var("SSH_CONNECTION").is_some() || var("SSH_TTY").is_some() {
+        return Vec::new();
+    }
+
+    let commands: &[&str] = if cfg!(target_os = "macos") {
+        &["pbcopy"]
+    } else if cfg!(windows) {
+        &["clip"]
+    } else if var("WAYLAND_DISPLAY").is_some() {
+        &["wl-copy", "xclip -selection clipboard", "xsel --clipboard --input"]
+    } else if var("DISPLAY").is_some() {
+        &["xclip -selection clipboard", "xsel --clipboard --input"]
+    } else {
+        &[]
+    };
+    commands.iter().map(|command| command.to_string()).collect()
+}
+
+/// Pipes `text` to the first command that runs successfully, returning its program name
+pub fn copy_with_commands(commands: &[String], text: &str) -> Result<String, String> {
+    let mut errors = Vec::new();
+
+    for command in commands {
+        let mut parts = command.split_whitespace();
+        let Some(program) = parts.next() else {
+            continue;
+        };
+
+        let result = Command::new(program)
+            .args(parts)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .and_then(|mut child| {
+                if let Some(mut stdin) = child.stdin.take() {
+                    stdin.write_all(text.as_bytes())?;
+                }
+                child.wait()
+            });
+        match result {
+            Ok(status) if status.success() => return Ok(program.to_string()),
+            Ok(status) => errors.push(format!("{}: {}", program, status)),
+            Err(e) => errors.push(format!("{}: {}", program, e)),
+        }
+    }
+
+    if errors.is_empty() {
+        Err(String::from("no clipboard command is available"))
+    } else {
+        Err(errors.join("; "))
+    }
+}
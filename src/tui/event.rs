@@ -1,4 +1,4 @@
-use crossterm::event::{self, Event as CrosstermEvent, KeyEvent};
+use crossterm::event::{self, Event as CrosstermEvent, KeyEvent, MouseEvent};
 use std::{
     sync::mpsc,
     thread,
@@ -12,6 +12,8 @@ pub enum Event {
     Tick,
     /// Key press.
     Key(KeyEvent),
+    /// Mouse click or scroll.
+    Mouse(MouseEvent),
     /// Terminal resize.
     Resize(u16, u16),
 }
@@ -38,18 +40,14 @@ impl EventHandler {
                         .unwrap_or(Duration::from_secs(0));
 
                     if event::poll(timeout).expect("failed to poll events") {
-                        match event::read().expect("failed to read event") {
-                            CrosstermEvent::Key(e) => {
-                                if sender.send(Event::Key(e)).is_err() {
-                                    return;
-                                }
-                            }
-                            CrosstermEvent::Resize(w, h) => {
-                                if sender.send(Event::Resize(w, h)).is_err() {
-                                    return;
-                                }
-                            }
-                            _ => {}
+                        let sent = match event::read().expect("failed to read event") {
+                            CrosstermEvent::Key(e) => sender.send(Event::Key(e)),
+                            CrosstermEvent::Mouse(e) => sender.send(Event::Mouse(e)),
+                            CrosstermEvent::Resize(w, h) => sender.send(Event::Resize(w, h)),
+                            _ => Ok(()),
+                        };
+                        if sent.is_err() {
+                            return;
                         }
                     }
 
@@ -1,17 +1,32 @@
-use crossterm::event::{self, KeyCode, KeyEventKind};
-use std::process::Command;
-use super::models::DisplayMode;
+use crossterm::event::{self, KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind};
+use super::keymap::{Action, Category};
+use super::models::{ClickTarget, DisplayMode, LinkFocus, ScrollState, TimelineFilter};
+use super::palette::PaletteAction;
 use super::state::App;
 
+/// Number of lines a mouse wheel step scrolls
+const MOUSE_SCROLL_LINES: i32 = 3;
+
 impl App {
     pub fn handle_key_event(&mut self, key: event::KeyEvent) {
         if key.kind != KeyEventKind::Press {
             return;
         }
 
-        let was_timeline = self.display_mode == DisplayMode::Timeline;
-        let was_at_leftmost = self.timeline_index == 0;
-        let was_left_key = key.code == KeyCode::Left || key.code == KeyCode::Char('h');
+        if self.palette.open {
+            self.handle_palette_keys(key);
+            return;
+        }
+
+        if self.search.prompt_open {
+            self.handle_search_keys(key);
+            return;
+        }
+
+        if self.help.is_some() {
+            self.handle_help_keys(key);
+            return;
+        }
 
         if self.display_mode == DisplayMode::Timeline {
             let filtered_events = self.get_filtered_events();
@@ -19,69 +34,373 @@ impl App {
                 self.timeline_event_index = 0;
             }
         }
+
+        let Some(action) = self.keymap.action(self.key_scope(), &key) else {
+            return;
+        };
+
+        match action {
+            Action::Help => {
+                self.help = Some(ScrollState::default());
+                return;
+            }
+            Action::CommandPalette => {
+                self.open_palette("");
+                return;
+            }
+            Action::Search => {
+                self.search.prompt_open = true;
+                self.search.query.clear();
+                self.update_search();
+                return;
+            }
+            Action::NextMatch if !self.search.matches.is_empty() => {
+                self.cycle_search_match(true);
+                return;
+            }
+            Action::PreviousMatch if !self.search.matches.is_empty() => {
+                self.cycle_search_match(false);
+                return;
+            }
+            Action::Copy => {
+                self.yank();
+                return;
+            }
+            Action::CycleTheme => {
+                self.cycle_theme();
+                return;
+            }
+            _ => {}
+        }
+
+        if self.handle_link_action(action) {
+            return;
+        }
+
+        if self.handle_scroll_action(action) {
+            return;
+        }
+
+        let was_timeline = self.display_mode == DisplayMode::Timeline;
+        let was_at_leftmost = self.timeline_index == 0;
         
-        match self.display_mode {
-            DisplayMode::Menu => self.handle_menu_keys(key),
-            DisplayMode::Timeline => {
-                if self.timeline_detail_view {
-                    self.handle_timeline_detail_keys(key)
-                } else {
-                    self.handle_timeline_keys(key)
-                }
-            },
-            DisplayMode::SkillsVisual => self.handle_skills_visual_keys(key),
-            DisplayMode::ProjectLinks => self.handle_project_links_keys(key),
-            _ => self.handle_content_keys(key),
+        match self.screens.screen_for(self.display_mode) {
+            Some(screen) => screen.handle_action(self, action),
+            None => self.handle_menu_action(action),
         }
         
-        if was_timeline && was_at_leftmost && was_left_key && 
+        if was_timeline && was_at_leftmost && action == Action::Left && 
            self.display_mode == DisplayMode::About {
             self.display_mode = DisplayMode::Menu;
-            self.menu_index = 3;
+            self.menu_index = self.screens.position(DisplayMode::Timeline).unwrap_or(self.menu_index);
             self.timeline_detail_view = false;
         }
     }
     
-    fn switch_to_selected_screen(&mut self) {
-        match self.menu_index {
-            0 => {
+    fn handle_palette_keys(&mut self, key: event::KeyEvent) {
+        let control = key.modifiers.contains(KeyModifiers::CONTROL);
+
+        match key.code {
+            KeyCode::Esc => {
+                self.palette.open = false;
+            }
+            KeyCode::Enter => {
+                self.palette.open = false;
+                if let Some(&entry) = self.palette.matches.get(self.palette.selected) {
+                    self.run_palette_action(self.palette_entries[entry].action.clone());
+                }
+            }
+            KeyCode::Char('p') if control => {
+                self.palette.selected = self.palette.selected.saturating_sub(1);
+            }
+            KeyCode::Char('n') if control && self.palette.selected + 1 < self.palette.matches.len() => {
+                self.palette.selected += 1;
+            }
+            KeyCode::Up => {
+                self.palette.selected = self.palette.selected.saturating_sub(1);
+            }
+            KeyCode::Down if self.palette.selected + 1 < self.palette.matches.len() => {
+                self.palette.selected += 1;
+            }
+            KeyCode::Backspace => {
+                self.palette.query.pop();
+                self.update_palette();
+            }
+            KeyCode::Char(c) if !control => {
+                self.palette.query.push(c);
+                self.update_palette();
+            }
+            _ => {}
+        }
+    }
+
+    /// Carries out a command palette entry
+    pub fn run_palette_action(&mut self, action: PaletteAction) {
+        match action {
+            PaletteAction::Screen(mode) => {
+                if let Some(index) = self.screens.position(mode) {
+                    self.menu_index = index;
+                    self.switch_to_selected_screen();
+                }
+            }
+            PaletteAction::TimelineEvent(index) => {
                 self.previous_mode = self.display_mode;
-                self.display_mode = DisplayMode::About;
-            },
-            1 => {
+                self.display_mode = DisplayMode::Timeline;
+                self.menu_index = self.screens.position(DisplayMode::Timeline).unwrap_or(self.menu_index);
+                self.timeline_filter = TimelineFilter::All;
+                self.timeline_index = index;
+                self.timeline_event_index = index;
+                self.timeline_detail_view = true;
+            }
+            PaletteAction::SkillCategory(index) => {
                 self.previous_mode = self.display_mode;
                 self.display_mode = DisplayMode::SkillsVisual;
-                self.skill_category_index = 0;
+                self.menu_index = self.screens.position(DisplayMode::SkillsVisual).unwrap_or(self.menu_index);
+                self.skill_category_index = index;
                 self.skills_page = 0;
-            },
-            2 => {
-                self.previous_mode = self.display_mode;
-                self.display_mode = DisplayMode::Projects;
-            },
-            3 => {
-                self.previous_mode = self.display_mode;
-                self.display_mode = DisplayMode::Timeline;
-                
-                self.timeline_index = 0;
-                self.timeline_event_index = self.timeline_index;
-            },
-            4 => {
+            }
+            PaletteAction::ProjectLink(index) => {
                 self.previous_mode = self.display_mode;
-                self.display_mode = DisplayMode::Contact;
-            },
+                self.display_mode = DisplayMode::ProjectLinks;
+                self.menu_index = self.screens.position(DisplayMode::ProjectLinks).unwrap_or(self.menu_index);
+                self.link_index = index;
+            }
+            PaletteAction::OpenUrl(url) => self.open_url(&url),
+            PaletteAction::Copy(text) => self.copy_to_clipboard(&text),
+        }
+    }
+
+    fn handle_help_keys(&mut self, key: event::KeyEvent) {
+        let action = self.keymap.action(self.key_scope(), &key);
+        let Some(help) = self.help.as_mut() else {
+            return;
+        };
+
+        match action {
+            Some(Action::Help | Action::Back | Action::Quit) => self.help = None,
+            Some(Action::Up) => help.scroll_by(-1),
+            Some(Action::Down) => help.scroll_by(1),
+            Some(Action::PageUp) => help.scroll_by(-help.page_size()),
+            Some(Action::PageDown) => help.scroll_by(help.page_size()),
+            Some(Action::Top) => help.offset = 0,
+            Some(Action::Bottom) => help.offset = help.max_offset(),
             _ => {}
         }
     }
 
-    fn handle_timeline_detail_keys(&mut self, key: event::KeyEvent) {
+    /// Describes what each action does on the current screen, from the screen's own actions and the shared ones
+    pub fn available_actions(&self) -> Vec<(Action, &'static str)> {
+        let mut actions = match self.screens.screen_for(self.display_mode) {
+            Some(screen) => screen.actions(self),
+            None => vec![
+                (Action::Up, "Previous section"),
+                (Action::Down, "Next section"),
+                (Action::Select, "Open the selected section"),
+                (Action::Quit, "Quit"),
+            ],
+        };
+
+        if self.scroll_target().is_some() {
+            actions.extend([
+                (Action::NextLink, "Focus the next link, Enter opens it"),
+                (Action::PreviousLink, "Focus the previous link"),
+                (Action::PageUp, "Scroll up a page"),
+                (Action::PageDown, "Scroll down a page"),
+                (Action::Top, "Scroll to the top"),
+                (Action::Bottom, "Scroll to the bottom"),
+            ]);
+            actions.push(if self.display_mode == DisplayMode::Contact {
+                (Action::Copy, "Copy a contact detail")
+            } else {
+                (Action::Copy, "Copy the focused link")
+            });
+        }
+
+        actions.extend([
+            (Action::Search, "Search every section"),
+            (Action::NextMatch, "Next search match"),
+            (Action::PreviousMatch, "Previous search match"),
+            (Action::CommandPalette, "Open the command palette"),
+            (Action::CycleTheme, "Switch to the next theme"),
+            (Action::Help, "Show this help"),
+        ]);
+        actions
+    }
+
+    /// Groups the bound actions of the current screen by category with the keys that trigger them
+    pub fn help_sections(&self) -> Vec<(Category, Vec<(String, &'static str)>)> {
+        let scope = self.key_scope();
+        let mut sections: Vec<(Category, Vec<(String, &'static str)>)> = Vec::new();
+
+        for (action, description) in self.available_actions() {
+            let keys = self.keymap.keys(scope, action);
+            if keys.is_empty() {
+                continue;
+            }
+
+            let keys = keys.iter().map(ToString::to_string).collect::<Vec<_>>().join("/");
+            match sections.iter_mut().find(|(category, _)| *category == action.category()) {
+                Some((_, entries)) => entries.push((keys, description)),
+                None => sections.push((action.category(), vec![(keys, description)])),
+            }
+        }
+
+        sections.sort_by_key(|(category, _)| *category);
+        sections
+    }
+
+    fn handle_search_keys(&mut self, key: event::KeyEvent) {
         match key.code {
-            KeyCode::Char('q') => {
+            KeyCode::Esc => {
+                self.search.prompt_open = false;
+                self.search.query.clear();
+                self.update_search();
+            }
+            KeyCode::Enter => {
+                self.search.prompt_open = false;
+                self.jump_to_selected_match();
+            }
+            KeyCode::Up if self.search.selected > 0 => {
+                self.search.selected -= 1;
+            }
+            KeyCode::Down if self.search.selected + 1 < self.search.matches.len() => {
+                self.search.selected += 1;
+            }
+            KeyCode::Backspace => {
+                self.search.query.pop();
+                self.update_search();
+            }
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.search.query.push(c);
+                self.update_search();
+            }
+            _ => {}
+        }
+    }
+
+    /// Scrolls the current pane with the wheel and clicks menu items, links, and timeline points
+    pub fn handle_mouse_event(&mut self, mouse: event::MouseEvent) {
+        if self.palette.open || self.search.prompt_open {
+            return;
+        }
+
+        if let Some(help) = self.help.as_mut() {
+            match mouse.kind {
+                MouseEventKind::ScrollDown => help.scroll_by(MOUSE_SCROLL_LINES),
+                MouseEventKind::ScrollUp => help.scroll_by(-MOUSE_SCROLL_LINES),
+                _ => {}
+            }
+            return;
+        }
+
+        match mouse.kind {
+            MouseEventKind::ScrollDown => self.scroll_current_pane(MOUSE_SCROLL_LINES),
+            MouseEventKind::ScrollUp => self.scroll_current_pane(-MOUSE_SCROLL_LINES),
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(target) = self.click_target_at(mouse.column, mouse.row) {
+                    self.handle_click(target);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn scroll_current_pane(&mut self, lines: i32) {
+        if let Some(target) = self.scroll_target() {
+            self.scroll_state_mut(target).scroll_by(lines);
+        }
+    }
+
+    fn handle_click(&mut self, target: ClickTarget) {
+        match target {
+            ClickTarget::MenuItem(index) => {
+                self.menu_index = index;
+                self.switch_to_selected_screen();
+            }
+            ClickTarget::ProjectLink(index) => {
+                self.link_index = index;
+                if let Some(url) = self.project_links.get(index).map(|link| link.url.clone()) {
+                    self.open_url(&url);
+                }
+            }
+            ClickTarget::TimelineEvent(index) => {
+                self.timeline_index = index;
+                self.timeline_event_index = index;
+            }
+            ClickTarget::InlineLink(mode, index) => {
+                self.link_focus = Some(LinkFocus { mode, index, reveal: false });
+                if let Some(link) = self.section_links(mode).get(index) {
+                    self.open_url(&link.url);
+                }
+            }
+        }
+    }
+
+    /// Cycles focus through the inline links of the current pane and opens the focused one
+    fn handle_link_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::NextLink => self.cycle_link_focus(true),
+            Action::PreviousLink => self.cycle_link_focus(false),
+            Action::Select => match self.focused_link() {
+                Some(link) => self.open_url(&link.url),
+                None => return false,
+            },
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// Scrolls the current pane by page or to either end, returning true if the action was handled
+    fn handle_scroll_action(&mut self, action: Action) -> bool {
+        let Some(target) = self.scroll_target() else {
+            return false;
+        };
+        let scroll = self.scroll_state_mut(target);
+
+        match action {
+            Action::PageDown => scroll.scroll_by(scroll.page_size()),
+            Action::PageUp => scroll.scroll_by(-scroll.page_size()),
+            Action::Top => scroll.offset = 0,
+            Action::Bottom => scroll.offset = scroll.max_offset(),
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// Opens the screen selected in the menu
+    pub fn switch_to_selected_screen(&mut self) {
+        let Some(screen) = self.screens.get(self.menu_index) else {
+            return;
+        };
+
+        self.previous_mode = self.display_mode;
+        self.display_mode = self.screens.mode(self.menu_index);
+        screen.enter(self);
+    }
+
+    /// Opens the previous (or next) screen of the menu, returning false at either end
+    pub fn step_screen(&mut self, forward: bool) -> bool {
+        let index = if forward { self.menu_index + 1 } else { self.menu_index.wrapping_sub(1) };
+        if index >= self.screens.len() {
+            return false;
+        }
+
+        self.menu_index = index;
+        self.switch_to_selected_screen();
+        true
+    }
+
+    pub(crate) fn handle_timeline_detail_action(&mut self, action: Action) {
+        match action {
+            Action::Quit => {
                 self.should_exit = true;
             }
-            KeyCode::Esc | KeyCode::Backspace => {
+            Action::Back => {
                 self.timeline_detail_view = false;
             }
-            KeyCode::Left | KeyCode::Char('h') => {
+            Action::Left => {
                 if self.timeline_index > 0 {
                     self.timeline_index -= 1;
                     self.timeline_event_index = self.timeline_index;
@@ -89,166 +408,131 @@ impl App {
                     self.timeline_detail_view = false;
                 }
             }
-            KeyCode::Right | KeyCode::Char('l') => {
-                if !self.timeline_events.is_empty() && self.timeline_index < self.timeline_events.len() - 1 {
-                    self.timeline_index += 1;
-                    self.timeline_event_index = self.timeline_index;
-                }
+            Action::Right if self.timeline_index + 1 < self.get_filtered_events().len() => {
+                self.timeline_index += 1;
+                self.timeline_event_index = self.timeline_index;
             }
             _ => {}
         }
     }
     
-    fn handle_timeline_keys(&mut self, key: event::KeyEvent) {
-        match key.code {
-            KeyCode::Char('q') => {
+    pub(crate) fn handle_timeline_action(&mut self, action: Action) {
+        match action {
+            Action::Quit => {
                 self.should_exit = true;
             }
-            KeyCode::Esc | KeyCode::Backspace => {
+            Action::Back => {
                 self.previous_mode = DisplayMode::Timeline;
-                self.menu_index = 3;
+                self.menu_index = self.screens.position(DisplayMode::Timeline).unwrap_or(self.menu_index);
                 self.display_mode = DisplayMode::Menu;
                 self.timeline_detail_view = false;
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                if self.menu_index > 0 {
-                    self.menu_index -= 1;
-                    self.previous_mode = DisplayMode::Timeline;
-                    self.display_mode = DisplayMode::Menu;
-                    self.switch_to_selected_screen();
-                }
+            Action::Up => {
+                self.step_screen(false);
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                if self.menu_index < 5 {
-                    self.menu_index += 1;
-                    self.previous_mode = DisplayMode::Timeline;
-                    self.display_mode = DisplayMode::Menu;
-                    self.switch_to_selected_screen();
-                }
+            Action::Down => {
+                self.step_screen(true);
             }
-            KeyCode::Left | KeyCode::Char('h') => {
-                if self.timeline_index > 0 {
-                    self.timeline_index -= 1;
-                    self.timeline_event_index = self.timeline_index; 
-                } else {
-                    return;
-                }
+            Action::Left if self.timeline_index > 0 => {
+                self.timeline_index -= 1;
+                self.timeline_event_index = self.timeline_index; 
             }
-            KeyCode::Right | KeyCode::Char('l') => {
-                if !self.timeline_events.is_empty() && self.timeline_index < self.timeline_events.len() - 1 {
-                    self.timeline_index += 1;
-                    self.timeline_event_index = self.timeline_index;
-                }
+            Action::Right if self.timeline_index + 1 < self.get_filtered_events().len() => {
+                self.timeline_index += 1;
+                self.timeline_event_index = self.timeline_index;
             }
-            KeyCode::Enter => {
-                if !self.timeline_events.is_empty() {
-                    self.timeline_detail_view = true;
-                }
+            Action::Select if !self.get_filtered_events().is_empty() => {
+                self.timeline_detail_view = true;
+            }
+            Action::NextFilter => {
+                self.cycle_timeline_filter(true);
+            }
+            Action::PreviousFilter => {
+                self.cycle_timeline_filter(false);
             }
             _ => {}
         }
     }
     
-    fn handle_skills_visual_keys(&mut self, key: event::KeyEvent) {
-        match key.code {
-            KeyCode::Char('q') => {
+    pub(crate) fn handle_skills_visual_action(&mut self, action: Action) {
+        match action {
+            Action::Quit => {
                 self.should_exit = true;
             }
-            KeyCode::Esc => {
+            Action::Back => {
                 self.previous_mode = self.display_mode;
                 self.display_mode = DisplayMode::Menu;
-                self.menu_index = 1;
+                self.menu_index = self.screens.position(DisplayMode::SkillsVisual).unwrap_or(self.menu_index);
                 self.skill_category_index = 0;
                 self.skills_page = 0;
             }
-            KeyCode::Up | KeyCode::Char('k') => {
+            Action::Up => {
                 if self.skill_category_index > 0 {
                     self.skill_category_index -= 1;
                     self.skills_page = 0;
                 } else {
-                    self.previous_mode = self.display_mode;
-                    self.display_mode = DisplayMode::About;
-                    self.menu_index = 0;
+                    self.step_screen(false);
                 }
             }
-            KeyCode::Down | KeyCode::Char('j') => {
+            Action::Down => {
                 if !self.skills_data.categories.is_empty() && 
                    self.skill_category_index < self.skills_data.categories.len() - 1 {
                     self.skill_category_index += 1;
                     self.skills_page = 0;
                 } else {
-                    self.previous_mode = self.display_mode;
-                    self.display_mode = DisplayMode::Projects;
-                    self.menu_index = 2;
+                    self.step_screen(true);
                 }
             }
-            KeyCode::Left | KeyCode::Char('h') => {
-                if self.skills_page > 0 {
-                    self.skills_page -= 1;
-                }
+            Action::Left if self.skills_page > 0 => {
+                self.skills_page -= 1;
             }
-            KeyCode::Right | KeyCode::Char('l') => {
-                if !self.skills_data.categories.is_empty() {
-                    self.skills_page += 1;
-                }
+            Action::Right if !self.skills_data.categories.is_empty() => {
+                self.skills_page += 1;
             }
             _ => {}
         }
     }
     
-    fn handle_project_links_keys(&mut self, key: event::KeyEvent) {
-        match key.code {
-            KeyCode::Char('q') => {
+    pub(crate) fn handle_project_links_action(&mut self, action: Action) {
+        match action {
+            Action::Quit => {
                 self.should_exit = true;
             }
-            KeyCode::Esc | KeyCode::Left | KeyCode::Char('h') => {
+            Action::Back | Action::Left => {
                 self.display_mode = DisplayMode::Projects;
                 self.link_index = 0;
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                if self.link_index > 0 {
-                    self.link_index -= 1;
-                }
+            Action::Up if self.link_index > 0 => {
+                self.link_index -= 1;
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                if !self.project_links.is_empty() {
-                    self.link_index = (self.link_index + 1).min(self.project_links.len() - 1);
-                }
+            Action::Down if !self.project_links.is_empty() => {
+                self.link_index = (self.link_index + 1).min(self.project_links.len() - 1);
             }
-            KeyCode::Enter => {
-                if !self.project_links.is_empty() {
-                    let link_index = self.link_index.min(self.project_links.len() - 1);
-                    let url = &self.project_links[link_index].url;
-                    
-                    if let Err(e) = Command::new("open").arg(url).spawn() {
-                        eprintln!("Failed to open URL: {}", e);
-                    }
-                }
+            Action::Select if !self.project_links.is_empty() => {
+                let link_index = self.link_index.min(self.project_links.len() - 1);
+                let url = self.project_links[link_index].url.clone();
+                self.open_url(&url);
             }
             _ => {}
         }
     }
     
 
-    fn handle_menu_keys(&mut self, key: event::KeyEvent) {
+    fn handle_menu_action(&mut self, action: Action) {
         if self.skip_auto_switch {
             self.skip_auto_switch = false;
             
-            match key.code {
-                KeyCode::Char('q') | KeyCode::Esc => {
+            match action {
+                Action::Quit => {
                     self.should_exit = true;
                 }
-                KeyCode::Up | KeyCode::Char('k') => {
-                    if self.menu_index > 0 {
-                        self.menu_index -= 1;
-                    }
+                Action::Up if self.menu_index > 0 => {
+                    self.menu_index -= 1;
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    if self.menu_index < 4 {
-                        self.menu_index += 1;
-                    }
+                Action::Down if self.menu_index + 1 < self.screens.len() => {
+                    self.menu_index += 1;
                 }
-                KeyCode::Enter => {
+                Action::Select => {
                     self.switch_to_selected_screen();
                 }
                 _ => {}
@@ -256,58 +540,40 @@ impl App {
             return;
         }
         
-        match key.code {
-            KeyCode::Char('q') | KeyCode::Esc => {
+        match action {
+            Action::Quit => {
                 self.should_exit = true;
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                if self.menu_index > 0 {
-                    self.menu_index -= 1;
-                    self.switch_to_selected_screen();
-                }
+            Action::Up => {
+                self.step_screen(false);
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                if self.menu_index < 4 {
-                    self.menu_index += 1;
-                    self.switch_to_selected_screen();
-                }
+            Action::Down => {
+                self.step_screen(true);
             }
-            KeyCode::Enter => {
+            Action::Select => {
                 self.switch_to_selected_screen();
             }
             _ => {}
         }
     }
 
-    fn handle_content_keys(&mut self, key: event::KeyEvent) {
-        match key.code {
-            KeyCode::Char('q') => {
+    /// Handles the actions of a scrollable text screen: moving between screens and returning to the menu
+    pub fn handle_content_action(&mut self, action: Action) {
+        match action {
+            Action::Quit => {
                 self.should_exit = true;
             }
-            KeyCode::Esc | KeyCode::Backspace => {
+            Action::Back => {
                 self.previous_mode = self.display_mode;
                 self.display_mode = DisplayMode::Menu;
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                if self.menu_index > 0 {
-                    self.menu_index -= 1;
-                    self.switch_to_selected_screen();
-                }
-            }
-            KeyCode::Down | KeyCode::Char('j') => {
-                if self.menu_index < 4 {
-                    self.menu_index += 1;
-                    self.switch_to_selected_screen();
-                }
+            Action::Up => {
+                self.step_screen(false);
             }
-            KeyCode::Right | KeyCode::Char('l') => {
-                if self.display_mode == DisplayMode::Projects {
-                    self.previous_mode = self.display_mode;
-                    self.display_mode = DisplayMode::ProjectLinks;
-                    self.link_index = 0;
-                }
+            Action::Down => {
+                self.step_screen(true);
             }
-            KeyCode::Enter => {
+            Action::Select => {
                 self.switch_to_selected_screen();
             }
             _ => {}
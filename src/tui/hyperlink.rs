@@ -0,0 +1,70 @@
+use ratatui::{backend::Backend, buffer::Buffer, layout::Rect};
+use std::{env, io};
+use unicode_width::UnicodeWidthStr;
+
+/// Environment variable that turns OSC 8 hyperlinks on (`1`) or off (`0`)
+pub const HYPERLINKS_ENV: &str = "HIRE_DAVID_PARKER_HYPERLINKS";
+
+/// Terminal programs, from `TERM_PROGRAM`, known to support OSC 8 hyperlinks
+const SUPPORTED_PROGRAMS: [&str; 6] = ["iTerm.app", "WezTerm", "vscode", "ghostty", "Hyper", "Tabby"];
+
+/// Terminal types, from `TERM`, known to support OSC 8 hyperlinks
+const SUPPORTED_TERMS: [&str; 5] = ["xterm-kitty", "xterm-ghostty", "wezterm", "foot", "alacritty"];
+
+/// Returns whether the current terminal should be sent OSC 8 hyperlinks
+pub fn detect() -> bool {
+    supported(|name| env::var(name).ok())
+}
+
+/// Decides hyperlink support from the environment variables returned by `var`
+pub fn supported(var: impl Fn(&str) -> Option<String>) -> bool {
+    if let Some(value) = var(HYPERLINKS_ENV) {
+        return !matches!(value.trim().to_ascii_lowercase().as_str(), "0" | "false" | "no" | "off");
+    }
+
+    let term = var("TERM").unwrap_or_default();
+    if term.is_empty() || term == "dumb" {
+        return false;
+    }
+
+    var("TERM_PROGRAM").is_some_and(|program| SUPPORTED_PROGRAMS.contains(&program.as_str()))
+        || SUPPORTED_TERMS.iter().any(|supported| term.starts_with(supported))
+        || var("VTE_VERSION").and_then(|version| version.parse::<u32>().ok()).is_some_and(|version| version >= 5000)
+        || ["KITTY_WINDOW_ID", "WT_SESSION", "KONSOLE_VERSION"].iter().any(|name| var(name).is_some())
+}
+
+/// Returns the escape sequence that starts a hyperlink to `url`, or ends one when `url` is empty
+pub fn osc8_sequence(url: &str) -> String {
+    let url: String = url.chars().filter(|c| !c.is_control()).collect();
+    format!("\x1b]8;;{}\x1b\\", url)
+}
+
+/// Redraws the cells under each target as a hyperlink, since ratatui's diff cannot hold escape sequences in cells
+pub fn draw<B: Backend>(backend: &mut B, buffer: &Buffer, targets: &[(Rect, String)]) -> io::Result<()> {
+    for (rect, url) in targets {
+        let area = rect.intersection(buffer.area);
+        if area.is_empty() {
+            continue;
+        }
+
+        for y in area.top()..area.bottom() {
+            let mut cells = Vec::new();
+            let mut x = area.left();
+            while x < area.right() {
+                let cell = &buffer[(x, y)];
+                cells.push((x, y, cell.clone()));
+                x += cell.symbol().width().max(1) as u16;
+            }
+
+            if let Some((_, _, first)) = cells.first_mut() {
+                first.set_symbol(&format!("{}{}", osc8_sequence(url), first.symbol()));
+            }
+            if let Some((_, _, last)) = cells.last_mut() {
+                last.set_symbol(&format!("{}{}", last.symbol(), osc8_sequence("")));
+            }
+            backend.draw(cells.iter().map(|(x, y, cell)| (*x, *y, cell)))?;
+        }
+    }
+
+    backend.flush()
+}
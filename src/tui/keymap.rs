@@ -0,0 +1,527 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::content::xdg_config_dir;
+
+/// Something a key can be bound to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    Back,
+    Up,
+    Down,
+    Left,
+    Right,
+    Select,
+    NextFilter,
+    PreviousFilter,
+    Search,
+    NextMatch,
+    PreviousMatch,
+    CommandPalette,
+    Copy,
+    CycleTheme,
+    NextLink,
+    PreviousLink,
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+    Help,
+}
+
+impl Action {
+    /// Every action, in the order they are documented
+    pub const ALL: [Action; 22] = [
+        Action::Quit,
+        Action::Back,
+        Action::Up,
+        Action::Down,
+        Action::Left,
+        Action::Right,
+        Action::Select,
+        Action::NextFilter,
+        Action::PreviousFilter,
+        Action::Search,
+        Action::NextMatch,
+        Action::PreviousMatch,
+        Action::CommandPalette,
+        Action::Copy,
+        Action::CycleTheme,
+        Action::NextLink,
+        Action::PreviousLink,
+        Action::PageUp,
+        Action::PageDown,
+        Action::Top,
+        Action::Bottom,
+        Action::Help,
+    ];
+
+    /// Returns the name used for the action in keymap files
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::Back => "back",
+            Action::Up => "up",
+            Action::Down => "down",
+            Action::Left => "left",
+            Action::Right => "right",
+            Action::Select => "select",
+            Action::NextFilter => "next_filter",
+            Action::PreviousFilter => "previous_filter",
+            Action::Search => "search",
+            Action::NextMatch => "next_match",
+            Action::PreviousMatch => "previous_match",
+            Action::CommandPalette => "command_palette",
+            Action::Copy => "copy",
+            Action::CycleTheme => "cycle_theme",
+            Action::NextLink => "next_link",
+            Action::PreviousLink => "previous_link",
+            Action::PageUp => "page_up",
+            Action::PageDown => "page_down",
+            Action::Top => "top",
+            Action::Bottom => "bottom",
+            Action::Help => "help",
+        }
+    }
+
+    /// Returns the group the action is listed under in the help overlay
+    pub fn category(self) -> Category {
+        match self {
+            Action::Quit | Action::Back | Action::Help | Action::CommandPalette | Action::Copy | Action::CycleTheme => Category::General,
+            Action::Up | Action::Down | Action::Left | Action::Right | Action::Select | Action::NextFilter | Action::PreviousFilter => {
+                Category::Navigation
+            }
+            Action::Search | Action::NextMatch | Action::PreviousMatch => Category::Search,
+            Action::NextLink | Action::PreviousLink => Category::Links,
+            Action::PageUp | Action::PageDown | Action::Top | Action::Bottom => Category::Scrolling,
+        }
+    }
+
+    /// Returns the action with the given keymap file name
+    pub fn from_name(name: &str) -> Option<Action> {
+        Action::ALL.into_iter().find(|action| action.name() == name)
+    }
+}
+
+/// A group of related actions in the help overlay
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Category {
+    Navigation,
+    Links,
+    Scrolling,
+    Search,
+    General,
+}
+
+impl Category {
+    /// Returns the heading shown for the group
+    pub fn label(self) -> &'static str {
+        match self {
+            Category::Navigation => "Navigation",
+            Category::Links => "Links",
+            Category::Scrolling => "Scrolling",
+            Category::Search => "Search",
+            Category::General => "General",
+        }
+    }
+}
+
+/// The set of screens a binding applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// Every screen, unless the screen binds the key itself
+    Global,
+    Menu,
+    /// The About, Skills, Projects, and Contact text panes
+    Content,
+    SkillsVisual,
+    ProjectLinks,
+    Timeline,
+    TimelineDetail,
+}
+
+impl Scope {
+    /// Every scope, in keymap file order
+    pub const ALL: [Scope; 7] = [
+        Scope::Global,
+        Scope::Menu,
+        Scope::Content,
+        Scope::SkillsVisual,
+        Scope::ProjectLinks,
+        Scope::Timeline,
+        Scope::TimelineDetail,
+    ];
+
+    /// Returns the table name used for the scope in keymap files
+    pub fn name(self) -> &'static str {
+        match self {
+            Scope::Global => "global",
+            Scope::Menu => "menu",
+            Scope::Content => "content",
+            Scope::SkillsVisual => "skills",
+            Scope::ProjectLinks => "project_links",
+            Scope::Timeline => "timeline",
+            Scope::TimelineDetail => "timeline_detail",
+        }
+    }
+}
+
+/// A key with its modifiers, as written in keymap files, e.g. `ctrl-p` or `pgdn`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }.normalized()
+    }
+
+    /// Drops the Shift modifier where the key already implies it, as in `N` or Shift-Tab
+    fn normalized(self) -> Self {
+        match self.code {
+            KeyCode::Char(_) | KeyCode::BackTab => Self { code: self.code, modifiers: self.modifiers - KeyModifiers::SHIFT },
+            _ => self,
+        }
+    }
+
+    /// Returns true if `key` is this key with the same modifiers
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        *self == KeyBinding::new(key.code, key.modifiers)
+    }
+}
+
+impl From<KeyCode> for KeyBinding {
+    fn from(code: KeyCode) -> Self {
+        KeyBinding::new(code, KeyModifiers::NONE)
+    }
+}
+
+impl FromStr for KeyBinding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = s.trim();
+        loop {
+            let lower = rest.to_ascii_lowercase();
+            let modifier = [("ctrl-", KeyModifiers::CONTROL), ("alt-", KeyModifiers::ALT), ("shift-", KeyModifiers::SHIFT)]
+                .into_iter()
+                .find(|(prefix, _)| lower.starts_with(prefix) && rest.len() > prefix.len());
+            match modifier {
+                Some((prefix, modifier)) => {
+                    modifiers |= modifier;
+                    rest = &rest[prefix.len()..];
+                }
+                None => break,
+            }
+        }
+
+        let code = match rest.to_ascii_lowercase().as_str() {
+            "esc" | "escape" => KeyCode::Esc,
+            "enter" | "return" => KeyCode::Enter,
+            "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "pageup" | "pgup" => KeyCode::PageUp,
+            "pagedown" | "pgdn" => KeyCode::PageDown,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "delete" | "del" => KeyCode::Delete,
+            "insert" | "ins" => KeyCode::Insert,
+            "space" => KeyCode::Char(' '),
+            name => match name.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                Some(n) if (1..=12).contains(&n) => KeyCode::F(n),
+                _ => {
+                    let mut chars = rest.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) if modifiers.contains(KeyModifiers::CONTROL) => KeyCode::Char(c.to_ascii_lowercase()),
+                        (Some(c), None) if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::Char(c.to_ascii_uppercase()),
+                        (Some(c), None) => KeyCode::Char(c),
+                        _ => return Err(format!("unknown key '{}'", s)),
+                    }
+                }
+            },
+        };
+
+        Ok(KeyBinding::new(code, modifiers))
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift-")?;
+        }
+
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) if self.modifiers.contains(KeyModifiers::CONTROL) => write!(f, "{}", c.to_ascii_uppercase()),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::BackTab => write!(f, "Shift-Tab"),
+            KeyCode::Backspace => write!(f, "Backspace"),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            KeyCode::PageUp => write!(f, "PgUp"),
+            KeyCode::PageDown => write!(f, "PgDn"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::Delete => write!(f, "Del"),
+            KeyCode::Insert => write!(f, "Ins"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+/// Converts key events into actions for the current screen
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    bindings: Vec<(Scope, KeyBinding, Action)>,
+    /// Actions a screen rebinds itself, so their global keys no longer apply there
+    replaced: Vec<(Scope, Action)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        use KeyCode::*;
+
+        let global = [
+            (Char('q').into(), Action::Quit),
+            (Esc.into(), Action::Back),
+            (Up.into(), Action::Up),
+            (Char('k').into(), Action::Up),
+            (Down.into(), Action::Down),
+            (Char('j').into(), Action::Down),
+            (Left.into(), Action::Left),
+            (Char('h').into(), Action::Left),
+            (Right.into(), Action::Right),
+            (Char('l').into(), Action::Right),
+            (Enter.into(), Action::Select),
+            (Char('/').into(), Action::Search),
+            (Char('n').into(), Action::NextMatch),
+            (Char('N').into(), Action::PreviousMatch),
+            (KeyBinding::new(Char('p'), KeyModifiers::CONTROL), Action::CommandPalette),
+            (Char('y').into(), Action::Copy),
+            (Char('t').into(), Action::CycleTheme),
+            (Tab.into(), Action::NextLink),
+            (BackTab.into(), Action::PreviousLink),
+            (PageUp.into(), Action::PageUp),
+            (PageDown.into(), Action::PageDown),
+            (Home.into(), Action::Top),
+            (End.into(), Action::Bottom),
+            (Char('?').into(), Action::Help),
+        ];
+
+        let mut bindings: Vec<(Scope, KeyBinding, Action)> =
+            global.into_iter().map(|(key, action)| (Scope::Global, key, action)).collect();
+        bindings.push((Scope::Menu, Esc.into(), Action::Quit));
+        for scope in [Scope::Content, Scope::Timeline, Scope::TimelineDetail] {
+            bindings.push((scope, Backspace.into(), Action::Back));
+        }
+        bindings.push((Scope::Timeline, Char('f').into(), Action::NextFilter));
+        bindings.push((Scope::Timeline, Char('F').into(), Action::PreviousFilter));
+
+        Self { bindings, replaced: Vec::new() }
+    }
+}
+
+impl Keymap {
+    /// Returns the action `key` triggers on a screen, preferring the screen's own bindings
+    pub fn action(&self, scope: Scope, key: &KeyEvent) -> Option<Action> {
+        self.find(scope, key)
+            .or_else(|| self.find(Scope::Global, key).filter(|action| !self.replaced.contains(&(scope, *action))))
+    }
+
+    fn find(&self, scope: Scope, key: &KeyEvent) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(binding_scope, binding, _)| *binding_scope == scope && binding.matches(key))
+            .map(|(_, _, action)| *action)
+    }
+
+    /// Returns the keys that trigger `action` on a screen, in binding order
+    pub fn keys(&self, scope: Scope, action: Action) -> Vec<KeyBinding> {
+        let mut keys: Vec<KeyBinding> = Vec::new();
+        for (binding_scope, key, bound) in &self.bindings {
+            let own = *binding_scope == scope;
+            let inherited = *binding_scope == Scope::Global
+                && !self.replaced.contains(&(scope, action))
+                && !self.bindings.iter().any(|(s, k, _)| *s == scope && k == key);
+            if *bound == action && (own || inherited) && !keys.contains(key) {
+                keys.push(*key);
+            }
+        }
+        keys
+    }
+
+    /// Names the first key that triggers `action` on a screen, for titles and messages that mention it
+    pub fn key_name(&self, scope: Scope, action: Action) -> Option<String> {
+        self.keys(scope, action).first().map(ToString::to_string)
+    }
+
+    /// Formats a footer hint such as `↑/k: Up` from the keys currently bound on a screen
+    pub fn hint(&self, scope: Scope, hint: &Hint) -> Option<String> {
+        let keys: Vec<String> = if let [action] = hint.actions {
+            self.keys(scope, *action).iter().take(hint.keys).map(ToString::to_string).collect()
+        } else {
+            hint.actions.iter().filter_map(|action| self.keys(scope, *action).first().map(ToString::to_string)).collect()
+        };
+
+        (!keys.is_empty()).then(|| format!("{}: {}", keys.join("/"), hint.label))
+    }
+
+    /// Joins the hints that have a key bound into a footer line
+    pub fn footer(&self, scope: Scope, hints: &[Hint]) -> String {
+        hints.iter().filter_map(|hint| self.hint(scope, hint)).collect::<Vec<_>>().join(" | ")
+    }
+
+    /// Rebinds actions from a TOML keymap, replacing the default keys of every action it lists
+    pub fn from_toml(source: &str) -> Result<Keymap, KeymapError> {
+        let file: HashMap<String, HashMap<String, KeyList>> =
+            toml::from_str(source).map_err(|e| KeymapError::Parse(e.message().to_string()))?;
+        let mut keymap = Keymap::default();
+
+        for scope in Scope::ALL {
+            let Some(table) = file.get(scope.name()) else {
+                continue;
+            };
+
+            let mut names: Vec<&String> = table.keys().collect();
+            names.sort();
+            for name in names {
+                let action = Action::from_name(name)
+                    .ok_or_else(|| KeymapError::Parse(format!("unknown action '{}' in [{}]", name, scope.name())))?;
+                let keys = table[name]
+                    .keys()
+                    .iter()
+                    .map(|key| key.parse::<KeyBinding>().map_err(|e| KeymapError::Parse(format!("{} for {} in [{}]", e, name, scope.name()))))
+                    .collect::<Result<Vec<_>, _>>()?;
+                keymap.bind(scope, action, &keys);
+            }
+        }
+
+        if let Some(scope) = file.keys().find(|name| !Scope::ALL.iter().any(|scope| scope.name() == name.as_str())) {
+            return Err(KeymapError::Parse(format!("unknown section [{}]", scope)));
+        }
+
+        Ok(keymap)
+    }
+
+    /// Reads a TOML keymap file
+    pub fn load(path: &Path) -> Result<Keymap, KeymapError> {
+        let source = fs::read_to_string(path).map_err(|source| KeymapError::Io { file: path.display().to_string(), source })?;
+        Self::from_toml(&source)
+    }
+
+    /// Replaces the keys bound to `action` in `scope`, including inherited global keys, and takes the keys away from other actions of that scope
+    fn bind(&mut self, scope: Scope, action: Action, keys: &[KeyBinding]) {
+        self.bindings.retain(|(s, key, bound)| *s != scope || (*bound != action && !keys.contains(key)));
+        self.bindings.extend(keys.iter().map(|key| (scope, *key, action)));
+        if scope != Scope::Global && !self.replaced.contains(&(scope, action)) {
+            self.replaced.push((scope, action));
+        }
+    }
+}
+
+/// A footer entry naming what one or more actions do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hint {
+    pub label: &'static str,
+    pub actions: &'static [Action],
+    /// How many keys to show when the hint has a single action
+    pub keys: usize,
+}
+
+impl Hint {
+    pub const QUIT: Hint = Hint::new("Quit", &[Action::Quit]);
+    pub const HELP: Hint = Hint::new("Help", &[Action::Help]);
+    pub const UP: Hint = Hint::new("Up", &[Action::Up]).with_keys(2);
+    pub const DOWN: Hint = Hint::new("Down", &[Action::Down]).with_keys(2);
+    pub const SELECT: Hint = Hint::new("Select", &[Action::Select]);
+    pub const COPY: Hint = Hint::new("Copy", &[Action::Copy]);
+    pub const SCROLL: Hint = Hint::new("Scroll", &[Action::PageUp, Action::PageDown]);
+    pub const RETURN: Hint = Hint::new("Return to Menu", &[Action::Back]);
+
+    /// Creates a hint showing the first key of each action
+    pub const fn new(label: &'static str, actions: &'static [Action]) -> Self {
+        Self { label, actions, keys: 1 }
+    }
+
+    /// Shows up to `keys` alternative keys for a single action, as in `↑/k: Up`
+    pub const fn with_keys(self, keys: usize) -> Self {
+        Self { keys, ..self }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyList {
+    fn keys(&self) -> Vec<&str> {
+        match self {
+            KeyList::One(key) => vec![key.as_str()],
+            KeyList::Many(keys) => keys.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+/// Errors raised while loading a keymap file
+#[derive(Debug)]
+pub enum KeymapError {
+    /// The keymap file could not be read
+    Io { file: String, source: io::Error },
+    /// The keymap file is not a valid keymap
+    Parse(String),
+}
+
+impl fmt::Display for KeymapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeymapError::Io { file, source } => write!(f, "failed to read keymap '{}': {}", file, source),
+            KeymapError::Parse(message) => write!(f, "invalid keymap: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for KeymapError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            KeymapError::Io { source, .. } => Some(source),
+            KeymapError::Parse(_) => None,
+        }
+    }
+}
+
+/// Returns the location of the user's keymap, e.g. `~/.config/hire-david-parker/keys.toml`
+pub fn xdg_keymap_file() -> Option<PathBuf> {
+    xdg_config_dir().map(|dir| dir.join("keys.toml"))
+}
@@ -1,21 +1,27 @@
 use crate::tui::models::Link;
 use pulldown_cmark::{Event, Options, Parser, Tag};
 use ratatui::{
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span, Text},
 };
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-/// Parse markdown text into Ratatui Text and extract hyperlinks
-pub fn parse_markdown(content: &str) -> (Text<'static>, Vec<Link>) {
+/// Creates the markdown parser shared by the TUI and every export format
+pub fn markdown_parser(content: &str) -> Parser<'_, '_> {
     let mut options = Options::empty();
     options.insert(Options::ENABLE_STRIKETHROUGH);
     
-    let parser = Parser::new_ext(content, options);
+    Parser::new_ext(content, options)
+}
+
+/// Parse markdown text into Ratatui Text and extract hyperlinks
+pub fn parse_markdown(content: &str) -> (Text<'static>, Vec<Link>) {
+    let parser = markdown_parser(content);
     
     let mut lines: Vec<Line> = Vec::new();
     let mut current_line: Vec<Span> = Vec::new();
     let mut active_styles = Vec::new();
-    let mut active_link_url: Option<String> = None;
+    let mut active_link: Option<Link> = None;
     let mut links: Vec<Link> = Vec::new();
     let mut current_line_idx = 0;
     let mut current_column = 0;
@@ -39,13 +45,11 @@ pub fn parse_markdown(content: &str) -> (Text<'static>, Vec<Link>) {
                             current_line_idx += 1;
                         }
                     },
-                    Tag::Paragraph => {
-                        if !current_line.is_empty() {
-                            lines.push(Line::from(current_line.clone()));
-                            current_line.clear();
-                            current_line_idx += 1;
-                            current_column = 0;
-                        }
+                    Tag::Paragraph if !current_line.is_empty() => {
+                        lines.push(Line::from(current_line.clone()));
+                        current_line.clear();
+                        current_line_idx += 1;
+                        current_column = 0;
                     },
                     Tag::Emphasis => {
                         active_styles.push(Style::default().add_modifier(Modifier::ITALIC));
@@ -57,18 +61,20 @@ pub fn parse_markdown(content: &str) -> (Text<'static>, Vec<Link>) {
                         active_styles.push(Style::default().add_modifier(Modifier::CROSSED_OUT));
                     },
                     Tag::Link(_link_type, url, _title) => {
-                        active_link_url = Some(url.to_string());
-                        active_styles.push(Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::UNDERLINED));
+                        active_link = Some(Link {
+                            text: String::new(),
+                            url: url.to_string(),
+                            line: current_line_idx,
+                            start_column: current_column,
+                            end_column: current_column,
+                        });
+                        active_styles.push(Style::default().add_modifier(Modifier::UNDERLINED));
                     },
-                    Tag::List(_) => {
-                        if !current_line.is_empty() {
-                            lines.push(Line::from(current_line.clone()));
-                            current_line.clear();
-                            current_line_idx += 1;
-                            current_column = 0;
-                        }
+                    Tag::List(_) if !current_line.is_empty() => {
+                        lines.push(Line::from(current_line.clone()));
+                        current_line.clear();
+                        current_line_idx += 1;
+                        current_column = 0;
                     },
                     Tag::Item => {
                         current_line.push(Span::raw("• "));
@@ -83,6 +89,7 @@ pub fn parse_markdown(content: &str) -> (Text<'static>, Vec<Link>) {
                         lines.push(Line::from(current_line.clone()));
                         current_line.clear();
                         current_line_idx += 1;
+                        current_column = 0;
                         lines.push(Line::from(Vec::new()));
                         current_line_idx += 1;
                         active_styles.pop();
@@ -109,7 +116,10 @@ pub fn parse_markdown(content: &str) -> (Text<'static>, Vec<Link>) {
                     },
                     Tag::Link(_, _, _) => {
                         active_styles.pop();
-                        active_link_url = None;
+                        if let Some(mut link) = active_link.take() {
+                            link.end_column = if link.line == current_line_idx { current_column } else { link.start_column + link.text.width() };
+                            links.push(link);
+                        }
                     },
                     Tag::Emphasis | Tag::Strong | Tag::Strikethrough => {
                         active_styles.pop();
@@ -130,25 +140,19 @@ pub fn parse_markdown(content: &str) -> (Text<'static>, Vec<Link>) {
                 }
                 
                 let text_str = text.to_string();
-                let start_column = current_column;
-                let end_column = start_column + text_str.len();
-                
-                if let Some(url) = &active_link_url {
-                    links.push(Link {
-                        text: text_str.clone(),
-                        url: url.clone(),
-                        line: current_line_idx,
-                        start_column,
-                        end_column,
-                    });
+                if let Some(link) = &mut active_link {
+                    link.text.push_str(&text_str);
                 }
                 
+                current_column += text_str.width();
                 current_line.push(Span::styled(text_str, style));
-                current_column = end_column;
             },
             Event::SoftBreak => {
                 current_line.push(Span::raw(" "));
                 current_column += 1;
+                if let Some(link) = &mut active_link {
+                    link.text.push(' ');
+                }
             },
             Event::HardBreak => {
                 lines.push(Line::from(current_line.clone()));
@@ -161,11 +165,12 @@ pub fn parse_markdown(content: &str) -> (Text<'static>, Vec<Link>) {
                     .add_modifier(Modifier::BOLD);
                 
                 let text_str = text.to_string();
-                let start_column = current_column;
-                let end_column = start_column + text_str.len();
+                if let Some(link) = &mut active_link {
+                    link.text.push_str(&text_str);
+                }
                 
+                current_column += text_str.width();
                 current_line.push(Span::styled(text_str, style));
-                current_column = end_column;
             },
             _ => {}
         }
@@ -177,3 +182,106 @@ pub fn parse_markdown(content: &str) -> (Text<'static>, Vec<Link>) {
     
     (Text::from(lines), links)
 }
+
+/// Returns the row and column where display column `column` of `line` lands once word-wrapped to `width`
+pub fn wrapped_position(line: &Line, column: usize, width: u16) -> (usize, usize) {
+    let width = usize::from(width.max(1));
+    let content: String = line.spans.iter().map(|span| span.content.as_ref()).collect();
+    let mut row = 0;
+    let mut x = 0;
+    let mut source = 0;
+
+    for word in split_words(&content) {
+        let word_width = word.width();
+        let is_space = word.chars().all(char::is_whitespace);
+
+        if is_space {
+            if x == 0 || x + word_width > width {
+                if (source..source + word_width).contains(&column) {
+                    return (row + usize::from(x > 0), 0);
+                }
+                if x > 0 {
+                    row += 1;
+                    x = 0;
+                }
+            } else {
+                if (source..source + word_width).contains(&column) {
+                    return (row, x + column - source);
+                }
+                x += word_width;
+            }
+            source += word_width;
+            continue;
+        }
+
+        if x > 0 && x + word_width > width {
+            row += 1;
+            x = 0;
+        }
+
+        for c in word.chars() {
+            let char_width = c.width().unwrap_or(0);
+            if x > 0 && x + char_width > width {
+                row += 1;
+                x = 0;
+            }
+            if source == column {
+                return (row, x);
+            }
+            x += char_width;
+            source += char_width;
+        }
+    }
+
+    (row, x)
+}
+
+fn split_words(content: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = 0;
+
+    for (i, c) in content.char_indices().skip(1) {
+        let previous = content[..i].chars().next_back().is_some_and(char::is_whitespace);
+        if previous != c.is_whitespace() {
+            words.push(&content[start..i]);
+            start = i;
+        }
+    }
+    if start < content.len() {
+        words.push(&content[start..]);
+    }
+
+    words
+}
+
+/// Patches `style` onto display columns `start..end` of `line`, splitting spans where needed
+pub fn style_columns(line: &mut Line<'static>, start: usize, end: usize, style: Style) {
+    let mut spans = Vec::with_capacity(line.spans.len() + 2);
+    let mut column = 0;
+
+    for span in line.spans.drain(..) {
+        let span_start = column;
+        column += span.content.width();
+        if column <= start || span_start >= end {
+            spans.push(span);
+            continue;
+        }
+
+        let mut segments: [String; 3] = Default::default();
+        let mut position = span_start;
+        for c in span.content.chars() {
+            let segment = if position < start { 0 } else if position < end { 1 } else { 2 };
+            segments[segment].push(c);
+            position += c.width().unwrap_or(0);
+        }
+
+        for (i, segment) in segments.into_iter().enumerate() {
+            if !segment.is_empty() {
+                let segment_style = if i == 1 { span.style.patch(style) } else { span.style };
+                spans.push(Span::styled(segment, segment_style));
+            }
+        }
+    }
+
+    line.spans = spans;
+}
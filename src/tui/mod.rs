@@ -1,9 +1,17 @@
+pub mod clipboard;
 pub mod event;
 pub mod handlers;
+pub mod hyperlink;
+pub mod keymap;
 pub mod markdown;
 pub mod models;
+pub mod opener;
+pub mod palette;
 pub mod runner;
+pub mod screen;
+pub mod search;
 pub mod state;
+pub mod theme;
 pub mod ui;
 
-pub use runner::run;
+pub use runner::{run, TuiOptions};
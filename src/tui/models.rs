@@ -1,4 +1,7 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 /// Skill data structure for visualization
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,8 +23,8 @@ pub struct SkillsData {
     pub categories: Vec<SkillCategory>,
 }
 
-/// Timeline event type - kept for compatibility but no longer used for filtering
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Timeline event category, read from the `type` field in `timeline.json`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum TimelineType {
     #[serde(rename = "career")]
     Career,
@@ -31,27 +34,293 @@ pub enum TimelineType {
     Certification,
     #[serde(rename = "project")]
     Project,
-    #[serde(other)]
+    #[default]
+    #[serde(rename = "other")]
     Other,
 }
 
+impl TimelineType {
+    /// Every timeline type, in display order
+    pub const ALL: [TimelineType; 5] = [
+        TimelineType::Career,
+        TimelineType::Education,
+        TimelineType::Certification,
+        TimelineType::Project,
+        TimelineType::Other,
+    ];
+
+    /// Returns the human readable name of the type
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimelineType::Career => "Career",
+            TimelineType::Education => "Education",
+            TimelineType::Certification => "Certification",
+            TimelineType::Project => "Project",
+            TimelineType::Other => "Other",
+        }
+    }
+
+    /// Returns the marker drawn for events of this type on the horizontal timeline
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            TimelineType::Career => "◆",
+            TimelineType::Education => "▲",
+            TimelineType::Certification => "★",
+            TimelineType::Project => "■",
+            TimelineType::Other => "●",
+        }
+    }
+}
+
+impl FromStr for TimelineType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        TimelineType::ALL
+            .into_iter()
+            .find(|event_type| event_type.label().eq_ignore_ascii_case(value.trim()))
+            .ok_or_else(|| format!("unknown timeline type '{}', expected career, education, certification, project, or other", value))
+    }
+}
+
+/// A calendar month on the timeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct YearMonth {
+    pub year: u16,
+    pub month: u8,
+}
+
+impl YearMonth {
+    const MONTH_NAMES: [&'static str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    /// Returns the current month according to the system clock (UTC)
+    pub fn today() -> Self {
+        let days = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() / 86_400)
+            .unwrap_or(0) as i64;
+
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        YearMonth { year: year as u16, month: month as u8 }
+    }
+
+    /// Returns the number of months since year zero, for arithmetic between months
+    pub fn ordinal(&self) -> u32 {
+        u32::from(self.year) * 12 + u32::from(self.month) - 1
+    }
+
+    /// Returns the abbreviated month name, e.g. "Mar 2021"
+    pub fn label(&self) -> String {
+        format!("{} {}", Self::MONTH_NAMES[usize::from(self.month - 1)], self.year)
+    }
+}
+
+/// A `start`/`end` date in `timeline.json`: `"YYYY-MM"` or `"present"`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum TimelineDate {
+    Month(YearMonth),
+    Present,
+}
+
+impl TimelineDate {
+    /// Returns the concrete month, treating `present` as `today`
+    pub fn resolve(&self, today: YearMonth) -> YearMonth {
+        match self {
+            TimelineDate::Month(month) => *month,
+            TimelineDate::Present => today,
+        }
+    }
+
+    /// Returns the label shown in the timeline details
+    pub fn label(&self) -> String {
+        match self {
+            TimelineDate::Month(month) => month.label(),
+            TimelineDate::Present => String::from("Present"),
+        }
+    }
+}
+
+impl TryFrom<String> for TimelineDate {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.eq_ignore_ascii_case("present") {
+            return Ok(TimelineDate::Present);
+        }
+
+        let invalid = || format!("invalid date '{}', expected YYYY-MM or \"present\"", value);
+        let (year, month) = value.split_once('-').ok_or_else(invalid)?;
+        if year.len() != 4 || month.len() != 2 {
+            return Err(invalid());
+        }
+
+        let year: u16 = year.parse().map_err(|_| invalid())?;
+        let month: u8 = month.parse().map_err(|_| invalid())?;
+        if !(1..=12).contains(&month) {
+            return Err(invalid());
+        }
+
+        Ok(TimelineDate::Month(YearMonth { year, month }))
+    }
+}
+
+impl From<TimelineDate> for String {
+    fn from(date: TimelineDate) -> Self {
+        date.to_string()
+    }
+}
+
+impl fmt::Display for TimelineDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimelineDate::Month(month) => write!(f, "{:04}-{:02}", month.year, month.month),
+            TimelineDate::Present => write!(f, "present"),
+        }
+    }
+}
+
+/// Formats a number of months as e.g. "2 yrs 3 mos"
+pub fn format_duration(months: u32) -> String {
+    let plural = |count: u32, unit: &str| {
+        if count == 1 { format!("1 {}", unit) } else { format!("{} {}s", count, unit) }
+    };
+
+    match (months / 12, months % 12) {
+        (0, m) => plural(m, "mo"),
+        (y, 0) => plural(y, "yr"),
+        (y, m) => format!("{} {}", plural(y, "yr"), plural(m, "mo")),
+    }
+}
+
 /// Timeline event structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimelineEvent {
     pub year: u16,
-    #[serde(rename = "type")]
+    #[serde(rename = "type", default)]
     pub event_type: TimelineType,
     pub title: String,
     pub organization: String,
     pub description: String,
     pub highlights: Option<Vec<String>>,
     pub technologies: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<TimelineDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<TimelineDate>,
 }
 
-/// Timeline filter type - kept as a placeholder but now only has one option
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+impl TimelineEvent {
+    /// Returns the first and last month covered by the event, if it has a `start` date
+    pub fn span(&self, today: YearMonth) -> Option<(YearMonth, YearMonth)> {
+        let start = self.start?.resolve(today);
+        let end = self.end.map_or(start, |end| end.resolve(today));
+        Some((start, end.max(start)))
+    }
+
+    /// Returns the number of months the event lasted, counting both ends
+    pub fn duration_months(&self, today: YearMonth) -> Option<u32> {
+        self.span(today).map(|(start, end)| end.ordinal() - start.ordinal() + 1)
+    }
+
+    /// Returns the first and last month ordinals of the event, using January of `year` when it has no `start` date
+    pub fn month_range(&self, today: YearMonth) -> (u32, u32) {
+        self.span(today)
+            .map(|(start, end)| (start.ordinal(), end.ordinal()))
+            .unwrap_or_else(|| {
+                let month = YearMonth { year: self.year, month: 1 }.ordinal();
+                (month, month)
+            })
+    }
+
+    /// Returns the range of years the event covers
+    pub fn year_range(&self, today: YearMonth) -> (u16, u16) {
+        self.span(today)
+            .map_or((self.year, self.year), |(start, end)| (start.year, end.year))
+    }
+
+    /// Returns the date range shown in the timeline details, e.g. "Mar 2021 - Present"
+    pub fn date_range_label(&self) -> Option<String> {
+        let start = self.start?;
+        Some(match self.end {
+            Some(end) if end != start => format!("{} - {}", start.label(), end.label()),
+            _ => start.label(),
+        })
+    }
+}
+
+/// Assigns every event that spans more than one month to a bar row, so overlapping spans never share a row
+pub fn timeline_span_lanes(events: &[&TimelineEvent], today: YearMonth) -> (Vec<Option<usize>>, usize) {
+    let mut lanes = vec![None; events.len()];
+    let mut lane_ends: Vec<u32> = Vec::new();
+
+    let mut spans: Vec<(usize, u32, u32)> = events
+        .iter()
+        .enumerate()
+        .filter_map(|(i, event)| event.span(today).map(|(start, end)| (i, start.ordinal(), end.ordinal())))
+        .filter(|(_, start, end)| end > start)
+        .collect();
+    spans.sort_by_key(|(_, start, _)| *start);
+
+    for (i, start, end) in spans {
+        let lane = lane_ends.iter().position(|lane_end| *lane_end < start).unwrap_or(lane_ends.len());
+        if lane == lane_ends.len() {
+            lane_ends.push(end);
+        } else {
+            lane_ends[lane] = end;
+        }
+        lanes[i] = Some(lane);
+    }
+
+    (lanes, lane_ends.len())
+}
+
+/// Filter applied to the timeline view
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum TimelineFilter {
+    #[default]
     All,
+    Type(TimelineType),
+    Technology(String),
+    YearRange(u16, u16),
+}
+
+impl TimelineFilter {
+    /// Returns true if the event should be shown under this filter
+    pub fn matches(&self, event: &TimelineEvent) -> bool {
+        match self {
+            TimelineFilter::All => true,
+            TimelineFilter::Type(event_type) => event.event_type == *event_type,
+            TimelineFilter::Technology(technology) => event
+                .technologies
+                .as_ref()
+                .is_some_and(|techs| techs.iter().any(|t| t.eq_ignore_ascii_case(technology))),
+            TimelineFilter::YearRange(start, end) => {
+                let (first, last) = event.year_range(YearMonth::today());
+                first <= *end && last >= *start
+            }
+        }
+    }
+
+    /// Returns the human readable name of the filter
+    pub fn label(&self) -> String {
+        match self {
+            TimelineFilter::All => String::from("All"),
+            TimelineFilter::Type(event_type) => event_type.label().to_string(),
+            TimelineFilter::Technology(technology) => technology.clone(),
+            TimelineFilter::YearRange(start, end) => format!("{}-{}", start, end),
+        }
+    }
 }
 
 /// Complete timeline data
@@ -77,8 +346,86 @@ pub struct ProjectLink {
     pub url: String,
 }
 
-/// Display modes for the application
+/// Scroll position of a content pane, measured in wrapped lines
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScrollState {
+    pub offset: u16,
+    pub content_height: u16,
+    pub viewport_height: u16,
+}
+
+impl ScrollState {
+    /// Returns the largest offset that still fills the viewport
+    pub fn max_offset(&self) -> u16 {
+        self.content_height.saturating_sub(self.viewport_height)
+    }
+
+    /// Records the size of the last render and clamps the offset to it
+    pub fn set_dimensions(&mut self, content_height: u16, viewport_height: u16) {
+        self.content_height = content_height;
+        self.viewport_height = viewport_height;
+        self.offset = self.offset.min(self.max_offset());
+    }
+
+    /// Scrolls by `delta` lines, staying within the content
+    pub fn scroll_by(&mut self, delta: i32) {
+        let offset = (i32::from(self.offset) + delta).clamp(0, i32::from(self.max_offset()));
+        self.offset = offset as u16;
+    }
+
+    /// Returns the number of lines a page scroll moves, keeping one line of context
+    pub fn page_size(&self) -> i32 {
+        i32::from(self.viewport_height.saturating_sub(1).max(1))
+    }
+
+    /// Returns true if there are lines above the viewport
+    pub fn has_more_above(&self) -> bool {
+        self.offset > 0
+    }
+
+    /// Returns true if there are lines below the viewport
+    pub fn has_more_below(&self) -> bool {
+        self.offset < self.max_offset()
+    }
+}
+
+/// Something on screen that reacts to a mouse click
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClickTarget {
+    MenuItem(usize),
+    ProjectLink(usize),
+    TimelineEvent(usize),
+    InlineLink(DisplayMode, usize),
+}
+
+/// The inline markdown link focused in a pane, and whether the pane should scroll to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinkFocus {
+    pub mode: DisplayMode,
+    pub index: usize,
+    pub reveal: bool,
+}
+
+/// A short message shown in the footer, such as the result of opening a link
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusMessage {
+    pub text: String,
+    pub is_error: bool,
+    pub shown_at: Instant,
+}
+
+impl StatusMessage {
+    /// How long a status message stays visible
+    pub const DURATION: Duration = Duration::from_secs(5);
+
+    /// Returns true once the message has been visible for its full duration
+    pub fn is_expired(&self, now: Instant) -> bool {
+        now.duration_since(self.shown_at) >= Self::DURATION
+    }
+}
+
+/// Display modes for the application
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DisplayMode {
     Menu,
     About,
@@ -88,4 +435,7 @@ pub enum DisplayMode {
     ProjectLinks,
     Timeline,
     Contact,
-}
\ No newline at end of file
+    /// A screen added to the registry without a display mode of its own, by registry position
+    Custom(usize),
+}
+
@@ -0,0 +1,94 @@
+use std::env;
+use std::process::{Command, Stdio};
+use std::thread;
+
+/// Environment variable that overrides the command used to open URLs
+pub const BROWSER_ENV: &str = "HIRE_DAVID_PARKER_BROWSER";
+
+/// Opens URLs by starting an external command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlOpener {
+    commands: Vec<String>,
+}
+
+impl Default for UrlOpener {
+    fn default() -> Self {
+        Self::resolve(None)
+    }
+}
+
+impl UrlOpener {
+    /// Uses the given commands, tried in order; `%s` is replaced by the URL, which is appended otherwise
+    pub fn new<S: Into<String>>(commands: impl IntoIterator<Item = S>) -> Self {
+        Self { commands: commands.into_iter().map(Into::into).collect() }
+    }
+
+    /// Resolves the opener from the `--browser` flag, `HIRE_DAVID_PARKER_BROWSER`, `BROWSER`, then the platform default
+    pub fn resolve(browser: Option<String>) -> Self {
+        let configured = browser
+            .or_else(|| env::var(BROWSER_ENV).ok())
+            .or_else(|| env::var("BROWSER").ok())
+            .filter(|command| !command.trim().is_empty());
+
+        match configured {
+            Some(command) if cfg!(windows) => Self::new([command]),
+            Some(command) => Self::new(command.split(':').filter(|c| !c.trim().is_empty()).map(str::to_string)),
+            None => Self::platform_default(),
+        }
+    }
+
+    /// Returns the system's own URL handler
+    pub fn platform_default() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::new(["open"])
+        } else if cfg!(windows) {
+            Self::new(["explorer"])
+        } else {
+            Self::new(["xdg-open"])
+        }
+    }
+
+    /// Returns the commands tried when opening a URL
+    pub fn commands(&self) -> &[String] {
+        &self.commands
+    }
+
+    /// Starts the first command that can be spawned, returning its program name
+    ///
+    /// The command is reaped on a background thread so it does not linger as a zombie.
+    pub fn open(&self, url: &str) -> Result<String, String> {
+        let mut errors = Vec::new();
+
+        for command in &self.commands {
+            let mut parts = command.split_whitespace();
+            let Some(program) = parts.next() else {
+                continue;
+            };
+
+            let mut process = Command::new(program);
+            process.args(parts.map(|part| part.replace("%s", url)));
+            if !command.contains("%s") {
+                process.arg(url);
+            }
+
+            let spawned = process
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn();
+            match spawned {
+                Ok(mut child) => {
+                    thread::spawn(move || child.wait());
+                    return Ok(program.to_string());
+                }
+                Err(e) => errors.push(format!("{}: {}", program, e)),
+            }
+        }
+
+        if errors.is_empty() {
+            Err(String::from("no browser command is configured"))
+        } else {
+            Err(errors.join("; "))
+        }
+    }
+}
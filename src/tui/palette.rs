@@ -0,0 +1,137 @@
+use std::cmp::Reverse;
+
+use super::models::DisplayMode;
+use super::state::App;
+
+/// What picking a palette entry does
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaletteAction {
+    /// Shows a menu screen
+    Screen(DisplayMode),
+    /// Selects an event by its index into the sorted timeline events
+    TimelineEvent(usize),
+    /// Selects a skill category
+    SkillCategory(usize),
+    /// Selects a project link
+    ProjectLink(usize),
+    /// Opens a URL in the browser
+    OpenUrl(String),
+    /// Copies text to the clipboard
+    Copy(String),
+}
+
+/// One entry of the command palette
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaletteEntry {
+    pub label: String,
+    pub action: PaletteAction,
+}
+
+impl PaletteEntry {
+    fn new(label: String, action: PaletteAction) -> Self {
+        Self { label, action }
+    }
+}
+
+/// The palette prompt and the entries matching it
+#[derive(Debug, Clone, Default)]
+pub struct PaletteState {
+    pub open: bool,
+    pub query: String,
+    pub matches: Vec<usize>,
+    pub selected: usize,
+}
+
+/// Lists every screen, timeline event, skill category, project link, and contact action of the app
+pub fn palette_entries(app: &App) -> Vec<PaletteEntry> {
+    let mut entries: Vec<PaletteEntry> = app
+        .screens
+        .entries()
+        .into_iter()
+        .map(|(title, mode)| PaletteEntry::new(format!("Go to {}", title), PaletteAction::Screen(mode)))
+        .collect();
+
+    for (i, event) in app.timeline_events.iter().enumerate() {
+        entries.push(PaletteEntry::new(format!("Timeline: {} {}", event.year, event.title), PaletteAction::TimelineEvent(i)));
+    }
+
+    for (i, category) in app.skills_data.categories.iter().enumerate() {
+        entries.push(PaletteEntry::new(format!("Skills: {}", category.name), PaletteAction::SkillCategory(i)));
+    }
+
+    for (i, link) in app.project_links.iter().enumerate() {
+        entries.push(PaletteEntry::new(format!("Project: {}", link.text), PaletteAction::ProjectLink(i)));
+    }
+
+    for link in &app.project_links {
+        entries.push(PaletteEntry::new(format!("Open link: {}", link.text), PaletteAction::OpenUrl(link.url.clone())));
+    }
+
+    for field in &app.contact_fields {
+        if let Some(url) = &field.url {
+            entries.push(PaletteEntry::new(format!("Open {}: {}", field.label, field.value), PaletteAction::OpenUrl(url.clone())));
+        }
+        let value = field.url.clone().unwrap_or_else(|| field.value.clone());
+        entries.push(PaletteEntry::new(format!("Copy {}: {}", field.label.to_lowercase(), field.value), PaletteAction::Copy(value)));
+    }
+
+    entries
+}
+
+/// Returns the indices of entries matching `query`, best match first
+pub fn filter_entries(entries: &[PaletteEntry], query: &str) -> Vec<usize> {
+    let mut scored: Vec<(usize, i32)> = entries
+        .iter()
+        .enumerate()
+        .filter_map(|(i, entry)| fuzzy_score(query, &entry.label).map(|score| (i, score)))
+        .collect();
+
+    scored.sort_by_key(|&(_, score)| Reverse(score));
+    scored.into_iter().map(|(i, _)| i).collect()
+}
+
+/// Scores `text` if it contains the characters of `query` in order, ignoring case and spaces; higher is better
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let query: Vec<char> = query.chars().filter(|c| !c.is_whitespace()).collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let Some(&first) = query.first() else {
+        return Some(0);
+    };
+
+    (0..text.len())
+        .filter(|&start| chars_match(text[start], first))
+        .filter_map(|start| score_from(&query, &text, start))
+        .max()
+}
+
+/// Scores a greedy match of `query` that starts at `start`
+fn score_from(query: &[char], text: &[char], start: usize) -> Option<i32> {
+    let mut score = 0;
+    let mut position = start;
+    let mut previous = None;
+
+    for &wanted in query {
+        let found = (position..text.len()).find(|&i| chars_match(text[i], wanted))?;
+
+        score += 1;
+        if found > 0 && previous == Some(found - 1) {
+            score += 5;
+        }
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        if previous.is_some() {
+            score -= (found - position).min(3) as i32;
+        }
+
+        previous = Some(found);
+        position = found + 1;
+    }
+
+    Some(score)
+}
+
+fn chars_match(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
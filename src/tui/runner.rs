@@ -1,7 +1,6 @@
-use crossterm::{execute, terminal};
 use ratatui::prelude::*;
-use std::{error::Error, io, sync::mpsc, panic, time::Duration};
-use super::{ui, event::{Event as AppEvent, EventHandler}, state::App};
+use std::{error::Error, sync::mpsc};
+use super::{clipboard, hyperlink, keymap::{self, Keymap}, opener::UrlOpener, theme::{self, ColorDepth, Theme, ThemeError}, ui, event::{Event as AppEvent, EventHandler}, state::App};
 
 pub trait EventHandlerTrait {
     fn receiver(&self) -> &mpsc::Receiver<AppEvent>;
@@ -13,13 +12,77 @@ impl EventHandlerTrait for EventHandler {
     }
 }
 
+/// Settings the TUI starts with
+#[derive(Debug, Clone)]
+pub struct TuiOptions {
+    pub url_opener: UrlOpener,
+    pub themes: Vec<Theme>,
+    pub theme_index: usize,
+    pub color_depth: ColorDepth,
+    pub keymap: Keymap,
+    /// Problems found while loading the settings, shown in the footer on startup
+    pub warnings: Vec<String>,
+}
+
+impl Default for TuiOptions {
+    fn default() -> Self {
+        Self {
+            url_opener: UrlOpener::default(),
+            themes: Theme::builtin(),
+            theme_index: 0,
+            color_depth: ColorDepth::TrueColor,
+            keymap: Keymap::default(),
+            warnings: Vec::new(),
+        }
+    }
+}
+
+impl TuiOptions {
+    /// Resolves the settings from the `--browser` and `--theme` flags, the environment, the terminal, and the user's config directory
+    pub fn resolve(browser: Option<String>, theme_name: Option<String>) -> Result<Self, ThemeError> {
+        let (mut themes, errors) = theme::load_themes(theme::xdg_theme_dir().as_deref());
+        let mut warnings: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        let theme_index = match theme_name
+            .or_else(|| std::env::var(theme::THEME_ENV).ok())
+            .filter(|name| !name.trim().is_empty())
+        {
+            Some(name) => theme::select_theme(&mut themes, &name)?,
+            None => 0,
+        };
+        let keymap = match keymap::xdg_keymap_file().filter(|path| path.is_file()) {
+            Some(path) => Keymap::load(&path).unwrap_or_else(|e| {
+                warnings.push(e.to_string());
+                Keymap::default()
+            }),
+            None => Keymap::default(),
+        };
+
+        Ok(Self {
+            url_opener: UrlOpener::resolve(browser),
+            themes,
+            theme_index,
+            color_depth: ColorDepth::detect(),
+            keymap,
+            warnings,
+        })
+    }
+
+    /// Joins every warning into one footer message, so a bad theme does not hide a bad keymap
+    pub fn warning_status(&self) -> Option<String> {
+        (!self.warnings.is_empty()).then(|| self.warnings.join("; "))
+    }
+}
+
 #[cfg(not(test))]
-pub fn run() -> Result<(), Box<dyn Error>> {
+pub fn run(options: TuiOptions) -> Result<(), Box<dyn Error>> {
+    use crossterm::{event::{DisableMouseCapture, EnableMouseCapture}, execute, terminal};
+    use std::{io, panic, time::Duration};
+
     let original_hook = panic::take_hook();
     panic::set_hook(Box::new(move |panic_info| {
         let _ = terminal::disable_raw_mode();
         let mut stdout = io::stdout();
-        let _ = execute!(stdout, terminal::LeaveAlternateScreen);
+        let _ = execute!(stdout, DisableMouseCapture, terminal::LeaveAlternateScreen);
         
         original_hook(panic_info);
     }));
@@ -31,7 +94,7 @@ pub fn run() -> Result<(), Box<dyn Error>> {
         
         static INTERRUPTED: AtomicBool = AtomicBool::new(false);
         
-        if let Ok(mut signals) = Signals::new(&[SIGINT]) {
+        if let Ok(mut signals) = Signals::new([SIGINT]) {
             std::thread::spawn(move || {
                 for _ in signals.forever() {
                     INTERRUPTED.store(true, Ordering::SeqCst);
@@ -42,11 +105,23 @@ pub fn run() -> Result<(), Box<dyn Error>> {
 
     terminal::enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, terminal::EnterAlternateScreen)?;
+    execute!(stdout, terminal::EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
+    let warning = options.warning_status();
     let mut app = App::new();
+    app.url_opener = options.url_opener;
+    app.themes = options.themes;
+    app.theme_index = options.theme_index;
+    app.color_depth = options.color_depth;
+    app.keymap = options.keymap;
+    if let Some(warning) = warning {
+        app.set_status(warning, true);
+    } else if let Some(error) = app.content_error(&crate::sections::MANIFEST_FILES) {
+        app.set_status(format!("{}, showing the built-in sections", error), true);
+    }
+    app.hyperlinks = hyperlink::detect();
     
     let event_handler = EventHandler::new(Duration::from_millis(100));
 
@@ -55,6 +130,7 @@ pub fn run() -> Result<(), Box<dyn Error>> {
     terminal::disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
+        DisableMouseCapture,
         terminal::LeaveAlternateScreen
     )?;
     terminal.show_cursor()?;
@@ -77,8 +153,14 @@ pub fn run_app<B: Backend, H: EventHandlerTrait>(
     #[cfg(not(unix))]
     let check_interrupted = || false;
 
+    let mut pending_copy = None;
+
     loop {
-        terminal.draw(|f| ui::render(f, app))?;
+        let frame = terminal.draw(|f| ui::render(f, app))?;
+        if !app.hyperlink_targets.is_empty() {
+            let buffer = frame.buffer.clone();
+            hyperlink::draw(terminal.backend_mut(), &buffer, &app.hyperlink_targets)?;
+        }
         
         if check_interrupted() {
             app.should_exit = true;
@@ -89,10 +171,31 @@ pub fn run_app<B: Backend, H: EventHandlerTrait>(
                 AppEvent::Key(key) => {
                     app.handle_key_event(key);
                 }
-                AppEvent::Tick => {}
+                AppEvent::Mouse(mouse) => {
+                    app.handle_mouse_event(mouse);
+                }
+                AppEvent::Tick => {
+                    app.expire_status(std::time::Instant::now());
+                }
                 _ => {}
             }
         }
+
+        if let Some(text) = app.clipboard_request.take() {
+            let receiver = clipboard::copy_text(&mut std::io::stdout(), &text, clipboard::osc52_supported(), clipboard::local_commands());
+            pending_copy = Some((text, receiver));
+        }
+
+        if let Some((text, receiver)) = &pending_copy {
+            match receiver.try_recv() {
+                Ok(result) => {
+                    app.finish_copy(text, result);
+                    pending_copy = None;
+                }
+                Err(mpsc::TryRecvError::Empty) => {}
+                Err(mpsc::TryRecvError::Disconnected) => pending_copy = None,
+            }
+        }
         
         if app.should_exit {
             break;
@@ -103,6 +206,6 @@ pub fn run_app<B: Backend, H: EventHandlerTrait>(
 }
 
 #[cfg(test)]
-pub fn run() -> Result<(), Box<dyn Error>> {
+pub fn run(_options: TuiOptions) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
@@ -0,0 +1,540 @@
+use ratatui::{layout::Rect, Frame};
+use std::fmt;
+use std::rc::Rc;
+
+use crate::content::ContentError;
+use crate::sections::{load_section_content, BuiltinSection, Section};
+
+use super::keymap::{Action, Hint, Scope};
+use super::models::DisplayMode;
+use super::state::App;
+use super::ui;
+
+/// A section of the resume listed in the menu sidebar
+pub trait Screen {
+    /// Returns the label shown in the menu sidebar and the command palette
+    fn title(&self) -> &str;
+
+    /// Draws the screen into the content pane
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect);
+
+    /// Responds to an action the global keys left to the screen
+    fn handle_action(&self, app: &mut App, action: Action);
+
+    /// Returns the key hints shown in the footer while the screen is open
+    fn footer_hints(&self, app: &App) -> Vec<Hint>;
+
+    /// Describes what each screen-specific action does, for the help overlay
+    ///
+    /// The keys come from the keymap, and the actions every screen shares are added after these.
+    fn actions(&self, _app: &App) -> Vec<(Action, &'static str)> {
+        vec![
+            (Action::Up, "Previous section"),
+            (Action::Down, "Next section"),
+            (Action::Back, "Return to the menu"),
+            (Action::Quit, "Quit"),
+        ]
+    }
+
+    /// Returns the keymap scope of the screen in its current mode
+    fn scope(&self, _app: &App) -> Scope {
+        Scope::Content
+    }
+
+    /// Returns true if the screen's current mode is a text pane that scrolls and focuses links
+    fn scrolls(&self, _app: &App) -> bool {
+        self.markdown().is_some()
+    }
+
+    /// Returns the content files the screen's current mode is drawn from, whose load errors replace it
+    fn content_files(&self, _app: &App) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// Returns the display modes the screen draws, the first being the one it opens in
+    ///
+    /// Screens without modes of their own are given a [`DisplayMode::Custom`] when registered.
+    fn modes(&self) -> &[DisplayMode] {
+        &[]
+    }
+
+    /// Resets the screen's state when it is opened from the menu
+    fn enter(&self, _app: &mut App) {}
+
+    /// Returns the markdown of a screen that scrolls, links and searches like the built-in sections
+    fn markdown(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// The screens of the menu sidebar, in order
+#[derive(Clone)]
+pub struct ScreenRegistry {
+    screens: Vec<Rc<dyn Screen>>,
+}
+
+impl Default for ScreenRegistry {
+    fn default() -> Self {
+        let mut registry = Self::new();
+        registry.register(AboutScreen);
+        registry.register(SkillsScreen);
+        registry.register(ProjectsScreen);
+        registry.register(TimelineScreen);
+        registry.register(ContactScreen);
+        registry
+    }
+}
+
+impl fmt::Debug for ScreenRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.screens.iter().map(|screen| screen.title())).finish()
+    }
+}
+
+impl ScreenRegistry {
+    /// Creates a registry without any screens
+    pub fn new() -> Self {
+        Self { screens: Vec::new() }
+    }
+
+    /// Creates a registry with the sections of a manifest, collecting the errors of custom sections that failed to load
+    pub fn from_sections(sections: &[Section], errors: &mut Vec<ContentError>) -> Self {
+        let mut registry = Self::new();
+        for section in sections {
+            match section {
+                Section::Builtin(BuiltinSection::About) => registry.register(AboutScreen),
+                Section::Builtin(BuiltinSection::Skills) => registry.register(SkillsScreen),
+                Section::Builtin(BuiltinSection::Projects) => registry.register(ProjectsScreen),
+                Section::Builtin(BuiltinSection::Timeline) => registry.register(TimelineScreen),
+                Section::Builtin(BuiltinSection::Contact) => registry.register(ContactScreen),
+                Section::Custom { title, markdown, data } => {
+                    let content = load_section_content(markdown, data.as_deref()).map_err(|error| errors.push(error)).unwrap_or_default();
+                    registry.register(SectionScreen::new(title, markdown, data.as_deref(), content))
+                }
+            };
+        }
+        registry
+    }
+
+    /// Adds a screen to the end of the menu and returns the display mode it opens in
+    pub fn register(&mut self, screen: impl Screen + 'static) -> DisplayMode {
+        self.screens.push(Rc::new(screen));
+        self.mode(self.screens.len() - 1)
+    }
+
+    pub fn len(&self) -> usize {
+        self.screens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.screens.is_empty()
+    }
+
+    /// Returns the screen at a menu position
+    pub fn get(&self, index: usize) -> Option<Rc<dyn Screen>> {
+        self.screens.get(index).cloned()
+    }
+
+    /// Returns the display mode the screen at a menu position opens in
+    pub fn mode(&self, index: usize) -> DisplayMode {
+        self.screens
+            .get(index)
+            .and_then(|screen| screen.modes().first().copied())
+            .unwrap_or(DisplayMode::Custom(index))
+    }
+
+    /// Returns the menu position of the screen that draws a display mode
+    pub fn position(&self, mode: DisplayMode) -> Option<usize> {
+        self.screens.iter().enumerate().position(|(index, screen)| {
+            screen.modes().contains(&mode) || (screen.modes().is_empty() && mode == DisplayMode::Custom(index))
+        })
+    }
+
+    /// Returns the screen that draws a display mode, if any
+    pub fn screen_for(&self, mode: DisplayMode) -> Option<Rc<dyn Screen>> {
+        self.position(mode).and_then(|index| self.get(index))
+    }
+
+    /// Returns the markdown of the screen that draws a display mode, if it has any
+    pub fn markdown(&self, mode: DisplayMode) -> Option<&str> {
+        self.position(mode).and_then(|index| self.screens[index].markdown())
+    }
+
+    /// Returns the menu label and opening display mode of every screen, in order
+    pub fn entries(&self) -> Vec<(String, DisplayMode)> {
+        self.screens.iter().enumerate().map(|(index, screen)| (screen.title().to_string(), self.mode(index))).collect()
+    }
+}
+
+/// The About Me section, also previewed while the menu has focus
+pub struct AboutScreen;
+
+impl Screen for AboutScreen {
+    fn title(&self) -> &str {
+        "About Me"
+    }
+
+    fn modes(&self) -> &[DisplayMode] {
+        &[DisplayMode::About]
+    }
+
+    fn scrolls(&self, _app: &App) -> bool {
+        true
+    }
+
+    fn content_files(&self, _app: &App) -> Vec<&str> {
+        vec!["about.md"]
+    }
+
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        ui::render_about(f, app, area);
+    }
+
+    fn handle_action(&self, app: &mut App, action: Action) {
+        app.handle_content_action(action);
+    }
+
+    fn footer_hints(&self, _app: &App) -> Vec<Hint> {
+        vec![Hint::QUIT, Hint::HELP, Hint::UP, Hint::DOWN, Hint::SCROLL, Hint::RETURN]
+    }
+}
+
+/// The skill meters by category, with the skills markdown behind them
+pub struct SkillsScreen;
+
+impl Screen for SkillsScreen {
+    fn title(&self) -> &str {
+        "Skills"
+    }
+
+    fn modes(&self) -> &[DisplayMode] {
+        &[DisplayMode::SkillsVisual, DisplayMode::Skills]
+    }
+
+    fn scope(&self, app: &App) -> Scope {
+        if app.display_mode == DisplayMode::Skills {
+            Scope::Content
+        } else {
+            Scope::SkillsVisual
+        }
+    }
+
+    fn scrolls(&self, app: &App) -> bool {
+        app.display_mode == DisplayMode::Skills
+    }
+
+    fn content_files(&self, app: &App) -> Vec<&str> {
+        if app.display_mode == DisplayMode::Skills {
+            vec!["skills.md"]
+        } else {
+            vec!["skills.json"]
+        }
+    }
+
+    fn enter(&self, app: &mut App) {
+        app.skill_category_index = 0;
+        app.skills_page = 0;
+    }
+
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        if app.display_mode == DisplayMode::Skills {
+            ui::render_skills(f, app, area);
+        } else {
+            ui::render_skills_visual(f, app, area);
+        }
+    }
+
+    fn handle_action(&self, app: &mut App, action: Action) {
+        if app.display_mode == DisplayMode::Skills {
+            app.handle_content_action(action);
+        } else {
+            app.handle_skills_visual_action(action);
+        }
+    }
+
+    fn footer_hints(&self, app: &App) -> Vec<Hint> {
+        if app.display_mode == DisplayMode::Skills {
+            return vec![Hint::QUIT, Hint::HELP, Hint::UP, Hint::DOWN, Hint::SCROLL, Hint::RETURN];
+        }
+
+        let has_multiple_pages = if !app.skills_data.categories.is_empty() {
+            let category_index = app.skill_category_index.min(app.skills_data.categories.len() - 1);
+            let category = &app.skills_data.categories[category_index];
+
+            category.skills.len() > app.skills_per_page
+        } else {
+            false
+        };
+
+        let mut hints = vec![Hint::QUIT, Hint::HELP, Hint::new("Categories", &[Action::Up, Action::Down])];
+        if has_multiple_pages {
+            hints.push(Hint::new("Pages", &[Action::Left, Action::Right]));
+        }
+        hints.push(Hint::RETURN);
+        hints
+    }
+
+    fn actions(&self, app: &App) -> Vec<(Action, &'static str)> {
+        if app.display_mode == DisplayMode::Skills {
+            return vec![
+                (Action::Up, "Previous section"),
+                (Action::Down, "Next section"),
+                (Action::Back, "Return to the menu"),
+                (Action::Quit, "Quit"),
+            ];
+        }
+
+        vec![
+            (Action::Up, "Previous category"),
+            (Action::Down, "Next category"),
+            (Action::Left, "Previous page"),
+            (Action::Right, "Next page"),
+            (Action::Back, "Return to the menu"),
+            (Action::Quit, "Quit"),
+        ]
+    }
+}
+
+/// The projects markdown and the list of project links it opens with →
+pub struct ProjectsScreen;
+
+impl Screen for ProjectsScreen {
+    fn title(&self) -> &str {
+        "Projects"
+    }
+
+    fn modes(&self) -> &[DisplayMode] {
+        &[DisplayMode::Projects, DisplayMode::ProjectLinks]
+    }
+
+    fn scope(&self, app: &App) -> Scope {
+        if app.display_mode == DisplayMode::ProjectLinks {
+            Scope::ProjectLinks
+        } else {
+            Scope::Content
+        }
+    }
+
+    fn scrolls(&self, app: &App) -> bool {
+        app.display_mode == DisplayMode::Projects
+    }
+
+    fn content_files(&self, app: &App) -> Vec<&str> {
+        if app.display_mode == DisplayMode::ProjectLinks {
+            vec!["projects.json"]
+        } else {
+            vec!["projects.md"]
+        }
+    }
+
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        if app.display_mode == DisplayMode::ProjectLinks {
+            ui::render_project_links(f, app, area);
+        } else {
+            ui::render_projects(f, app, area);
+        }
+    }
+
+    fn handle_action(&self, app: &mut App, action: Action) {
+        match (app.display_mode, action) {
+            (DisplayMode::ProjectLinks, _) => app.handle_project_links_action(action),
+            (_, Action::Right) => {
+                app.previous_mode = app.display_mode;
+                app.display_mode = DisplayMode::ProjectLinks;
+                app.link_index = 0;
+            }
+            _ => app.handle_content_action(action),
+        }
+    }
+
+    fn footer_hints(&self, app: &App) -> Vec<Hint> {
+        if app.display_mode == DisplayMode::ProjectLinks {
+            vec![Hint::QUIT, Hint::HELP, Hint::UP, Hint::DOWN, Hint::SELECT, Hint::COPY, Hint::RETURN]
+        } else {
+            vec![Hint::QUIT, Hint::HELP, Hint::UP, Hint::DOWN, Hint::SCROLL, Hint::RETURN]
+        }
+    }
+
+    fn actions(&self, app: &App) -> Vec<(Action, &'static str)> {
+        if app.display_mode == DisplayMode::ProjectLinks {
+            vec![
+                (Action::Up, "Previous link"),
+                (Action::Down, "Next link"),
+                (Action::Select, "Open the selected link"),
+                (Action::Left, "Return to projects"),
+                (Action::Back, "Return to projects"),
+                (Action::Copy, "Copy the selected link"),
+                (Action::Quit, "Quit"),
+            ]
+        } else {
+            vec![
+                (Action::Up, "Previous section"),
+                (Action::Down, "Next section"),
+                (Action::Right, "Browse the project links"),
+                (Action::Back, "Return to the menu"),
+                (Action::Quit, "Quit"),
+            ]
+        }
+    }
+}
+
+/// The horizontal career timeline and its event details
+pub struct TimelineScreen;
+
+impl Screen for TimelineScreen {
+    fn title(&self) -> &str {
+        "Timeline"
+    }
+
+    fn modes(&self) -> &[DisplayMode] {
+        &[DisplayMode::Timeline]
+    }
+
+    fn scope(&self, app: &App) -> Scope {
+        if app.timeline_detail_view {
+            Scope::TimelineDetail
+        } else {
+            Scope::Timeline
+        }
+    }
+
+    fn content_files(&self, _app: &App) -> Vec<&str> {
+        vec!["timeline.md", "timeline.json"]
+    }
+
+    fn enter(&self, app: &mut App) {
+        app.timeline_index = 0;
+        app.timeline_event_index = app.timeline_index;
+    }
+
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        ui::render_timeline(f, app, area);
+    }
+
+    fn handle_action(&self, app: &mut App, action: Action) {
+        if app.timeline_detail_view {
+            app.handle_timeline_detail_action(action);
+        } else {
+            app.handle_timeline_action(action);
+        }
+    }
+
+    fn footer_hints(&self, app: &App) -> Vec<Hint> {
+        if app.timeline_detail_view {
+            return vec![
+                Hint::QUIT,
+                Hint::HELP,
+                Hint::new("Previous", &[Action::Left]).with_keys(2),
+                Hint::new("Next", &[Action::Right]).with_keys(2),
+                Hint::new("Close details", &[Action::Back]),
+            ];
+        }
+
+        vec![
+            Hint::QUIT,
+            Hint::HELP,
+            Hint::new("Previous", &[Action::Left]).with_keys(2),
+            Hint::new("Next", &[Action::Right]).with_keys(2),
+            Hint::SELECT,
+            Hint::new("Filter", &[Action::NextFilter, Action::PreviousFilter]),
+            Hint::RETURN,
+        ]
+    }
+
+    fn actions(&self, app: &App) -> Vec<(Action, &'static str)> {
+        if app.timeline_detail_view {
+            return vec![
+                (Action::Left, "Previous event"),
+                (Action::Right, "Next event"),
+                (Action::Back, "Close the details"),
+                (Action::Quit, "Quit"),
+            ];
+        }
+
+        vec![
+            (Action::Up, "Previous section"),
+            (Action::Down, "Next section"),
+            (Action::Left, "Previous event"),
+            (Action::Right, "Next event"),
+            (Action::Select, "Show the event details"),
+            (Action::NextFilter, "Next event filter"),
+            (Action::PreviousFilter, "Previous event filter"),
+            (Action::Back, "Return to the menu"),
+            (Action::Quit, "Quit"),
+        ]
+    }
+}
+
+/// The contact details and their links
+pub struct ContactScreen;
+
+impl Screen for ContactScreen {
+    fn title(&self) -> &str {
+        "Contact"
+    }
+
+    fn modes(&self) -> &[DisplayMode] {
+        &[DisplayMode::Contact]
+    }
+
+    fn scrolls(&self, _app: &App) -> bool {
+        true
+    }
+
+    fn content_files(&self, _app: &App) -> Vec<&str> {
+        vec!["contact.md"]
+    }
+
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        ui::render_contact(f, app, area);
+    }
+
+    fn handle_action(&self, app: &mut App, action: Action) {
+        app.handle_content_action(action);
+    }
+
+    fn footer_hints(&self, _app: &App) -> Vec<Hint> {
+        vec![Hint::QUIT, Hint::HELP, Hint::new("Links", &[Action::NextLink]), Hint::COPY, Hint::SCROLL, Hint::RETURN]
+    }
+}
+
+/// A markdown section declared in the content manifest, such as "Open Source" or "Speaking"
+pub struct SectionScreen {
+    title: String,
+    files: Vec<String>,
+    content: String,
+}
+
+impl SectionScreen {
+    /// Creates a section from its loaded content and the files it was loaded from
+    pub fn new(title: &str, markdown: &str, data: Option<&str>, content: String) -> Self {
+        let files = std::iter::once(markdown).chain(data).map(str::to_string).collect();
+        Self { title: title.to_string(), files, content }
+    }
+}
+
+impl Screen for SectionScreen {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn markdown(&self) -> Option<&str> {
+        Some(&self.content)
+    }
+
+    fn content_files(&self, _app: &App) -> Vec<&str> {
+        self.files.iter().map(String::as_str).collect()
+    }
+
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        ui::render_section(f, app, app.display_mode, &self.title, area);
+    }
+
+    fn handle_action(&self, app: &mut App, action: Action) {
+        app.handle_content_action(action);
+    }
+
+    fn footer_hints(&self, _app: &App) -> Vec<Hint> {
+        vec![Hint::QUIT, Hint::HELP, Hint::UP, Hint::DOWN, Hint::SCROLL, Hint::RETURN]
+    }
+}
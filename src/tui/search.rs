@@ -0,0 +1,174 @@
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+
+use super::markdown::parse_markdown;
+use super::models::DisplayMode;
+use super::state::App;
+
+/// Where a search result lives and what selecting it jumps to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchLocation {
+    /// A line of a markdown section, as rendered
+    Line(DisplayMode, usize),
+    /// An index into the sorted timeline events
+    TimelineEvent(usize),
+    /// An index into the skill categories
+    SkillCategory(usize),
+    /// An index into the project links
+    ProjectLink(usize),
+}
+
+impl SearchLocation {
+    /// Returns the display mode that shows this location
+    pub fn display_mode(&self) -> DisplayMode {
+        match self {
+            SearchLocation::Line(mode, _) => *mode,
+            SearchLocation::TimelineEvent(_) => DisplayMode::Timeline,
+            SearchLocation::SkillCategory(_) => DisplayMode::SkillsVisual,
+            SearchLocation::ProjectLink(_) => DisplayMode::ProjectLinks,
+        }
+    }
+
+    /// Returns the section heading results are grouped under
+    pub fn section(&self) -> &'static str {
+        match self.display_mode() {
+            DisplayMode::Menu | DisplayMode::About => "About",
+            DisplayMode::Skills | DisplayMode::SkillsVisual => "Skills",
+            DisplayMode::Projects | DisplayMode::ProjectLinks => "Projects",
+            DisplayMode::Timeline => "Timeline",
+            DisplayMode::Contact => "Contact",
+            DisplayMode::Custom(_) => "Sections",
+        }
+    }
+}
+
+/// One searchable piece of content
+#[derive(Debug, Clone)]
+pub struct SearchEntry {
+    pub location: SearchLocation,
+    pub label: String,
+    text: String,
+}
+
+impl SearchEntry {
+    fn new(location: SearchLocation, label: String, text: &str) -> Self {
+        Self { location, label, text: text.to_ascii_lowercase() }
+    }
+}
+
+/// Every searchable line and data entry, in section order
+#[derive(Debug, Clone, Default)]
+pub struct SearchIndex {
+    pub entries: Vec<SearchEntry>,
+}
+
+impl SearchIndex {
+    /// Indexes the markdown sections and the JSON data of the app
+    pub fn build(app: &App) -> Self {
+        let mut entries = Vec::new();
+
+        index_markdown(&mut entries, DisplayMode::About, &app.about_content);
+        index_markdown(&mut entries, DisplayMode::Skills, &app.skills_content);
+        for (i, category) in app.skills_data.categories.iter().enumerate() {
+            let skills: Vec<&str> = category.skills.iter().map(|skill| skill.name.as_str()).collect();
+            let text = format!("{} {}", category.name, skills.join(" "));
+            entries.push(SearchEntry::new(SearchLocation::SkillCategory(i), format!("{}: {}", category.name, skills.join(", ")), &text));
+        }
+
+        index_markdown(&mut entries, DisplayMode::Projects, &app.projects_content);
+        for (i, link) in app.project_links.iter().enumerate() {
+            let text = format!("{} {}", link.text, link.url);
+            entries.push(SearchEntry::new(SearchLocation::ProjectLink(i), format!("{} ({})", link.text, link.url), &text));
+        }
+
+        index_markdown(&mut entries, DisplayMode::Timeline, &app.timeline_content);
+        for (i, event) in app.timeline_events.iter().enumerate() {
+            let mut fields = vec![event.year.to_string(), event.title.clone(), event.organization.clone(), event.description.clone()];
+            fields.extend(event.highlights.iter().flatten().cloned());
+            fields.extend(event.technologies.iter().flatten().cloned());
+            let label = format!("{} - {} ({})", event.year, event.title, event.organization);
+            entries.push(SearchEntry::new(SearchLocation::TimelineEvent(i), label, &fields.join(" ")));
+        }
+
+        index_markdown(&mut entries, DisplayMode::Contact, &app.contact_content);
+
+        for (_, mode) in app.screens.entries() {
+            if let (DisplayMode::Custom(_), Some(content)) = (mode, app.screens.markdown(mode)) {
+                index_markdown(&mut entries, mode, content);
+            }
+        }
+
+        Self { entries }
+    }
+
+    /// Returns the indices of entries containing `query`, ignoring ASCII case
+    pub fn search(&self, query: &str) -> Vec<usize> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let query = query.to_ascii_lowercase();
+        self.entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.text.contains(&query))
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// The search prompt and the matches it produced
+#[derive(Debug, Clone, Default)]
+pub struct SearchState {
+    pub query: String,
+    pub prompt_open: bool,
+    pub matches: Vec<usize>,
+    pub selected: usize,
+    pub pending_scroll: Option<(DisplayMode, usize)>,
+}
+
+fn index_markdown(entries: &mut Vec<SearchEntry>, mode: DisplayMode, content: &str) {
+    let (text, _) = parse_markdown(content);
+
+    for (i, line) in text.lines.iter().enumerate() {
+        let content: String = line.spans.iter().map(|span| span.content.as_ref()).collect();
+        let content = content.trim().trim_start_matches("->").trim_end_matches("<-").trim();
+        if !content.is_empty() {
+            entries.push(SearchEntry::new(SearchLocation::Line(mode, i), content.to_string(), content));
+        }
+    }
+}
+
+/// Splits spans so every occurrence of `query`, ignoring ASCII case, has `style` patched on
+pub fn highlight_matches(line: &mut Line<'static>, query: &str, style: Style) {
+    if query.is_empty() {
+        return;
+    }
+
+    let query = query.to_ascii_lowercase();
+    let mut spans = Vec::with_capacity(line.spans.len());
+
+    for span in line.spans.drain(..) {
+        let content = span.content.to_string();
+        let haystack = content.to_ascii_lowercase();
+        let mut rest = 0;
+
+        while let Some(found) = haystack[rest..].find(&query) {
+            let start = rest + found;
+            let end = start + query.len();
+            if start > rest {
+                spans.push(Span::styled(content[rest..start].to_string(), span.style));
+            }
+            spans.push(Span::styled(content[start..end].to_string(), span.style.patch(style)));
+            rest = end;
+        }
+
+        if rest == 0 {
+            spans.push(span);
+        } else if rest < content.len() {
+            spans.push(Span::styled(content[rest..].to_string(), span.style));
+        }
+    }
+
+    line.spans = spans;
+}
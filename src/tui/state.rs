@@ -1,7 +1,16 @@
-use super::models::{DisplayMode, SkillsData, TimelineEvent, TimelineFilter, TimelineType};
-use crate::{about, skills, projects, welcome, timeline, contact, load_timeline_data, load_project_links, ProjectLink};
-use std::error::Error;
-use std::path::Path;
+use super::keymap::{Action, Keymap, Scope};
+use super::markdown::parse_markdown;
+use super::models::{ClickTarget, DisplayMode, Link, LinkFocus, ScrollState, StatusMessage, SkillsData, TimelineEvent, TimelineFilter, TimelineType, YearMonth};
+use super::opener::UrlOpener;
+use super::screen::{Screen, ScreenRegistry};
+use super::palette::{filter_entries, palette_entries, PaletteEntry, PaletteState};
+use super::search::{SearchIndex, SearchLocation, SearchState};
+use super::theme::{ColorDepth, Theme};
+use ratatui::layout::{Position, Rect};
+use std::collections::HashMap;
+use std::time::Instant;
+use crate::contact::{parse_contact_fields, ContactField};
+use crate::{load_sections, about, skills, projects, welcome, timeline, contact, load_timeline_data, load_project_links, load_skills_data, ContentError, ProjectLink};
 
 pub struct App {
     pub menu_index: usize,
@@ -16,81 +25,422 @@ pub struct App {
     pub skills_content: String,
     pub skills_data: SkillsData,
     pub skills_page: usize,
+    /// Skills that fit on a page of the skill meters, as last laid out by the renderer
+    pub skills_per_page: usize,
     pub projects_content: String,
     pub project_links: Vec<ProjectLink>,
     pub welcome_content: String,
     pub timeline_content: String,
     pub contact_content: String,
+    pub contact_fields: Vec<ContactField>,
     pub timeline_events: Vec<TimelineEvent>,
     pub timeline_index: usize,
+    pub content_errors: Vec<ContentError>,
+    pub scroll: HashMap<DisplayMode, ScrollState>,
+    pub search_index: SearchIndex,
+    pub search: SearchState,
+    pub palette_entries: Vec<PaletteEntry>,
+    pub palette: PaletteState,
+    pub help: Option<ScrollState>,
+    pub clipboard_request: Option<String>,
+    pub click_targets: Vec<(Rect, ClickTarget)>,
+    pub link_focus: Option<LinkFocus>,
+    pub hyperlinks: bool,
+    pub hyperlink_targets: Vec<(Rect, String)>,
+    pub url_opener: UrlOpener,
+    pub themes: Vec<Theme>,
+    pub theme_index: usize,
+    pub color_depth: ColorDepth,
+    pub keymap: Keymap,
+    pub screens: ScreenRegistry,
+    pub status: Option<StatusMessage>,
     pub should_exit: bool,
     pub skip_auto_switch: bool,
 }
 
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl App {
     pub fn new() -> Self {
-        let mut timeline_events = load_timeline_data().unwrap_or_default();
+        let mut content_errors = Vec::new();
+
+        let mut timeline_events = collect_error(load_timeline_data(), &mut content_errors).unwrap_or_default();
         
-        timeline_events.sort_by_key(|event| event.year);
+        timeline_events.sort_by_key(|event| (event.year, event.start));
         
         let timeline_index = 0;
         
-        let project_links = load_project_links()
+        let project_links = collect_error(load_project_links(), &mut content_errors)
             .map(|pl| pl.links)
             .unwrap_or_default();
+
+        let about_content = collect_error(about(), &mut content_errors).unwrap_or_default();
+        let skills_content = collect_error(skills(), &mut content_errors).unwrap_or_default();
+        let skills_data = collect_error(load_skills_data(), &mut content_errors).unwrap_or_default();
+        let projects_content = collect_error(projects(), &mut content_errors).unwrap_or_default();
+        let welcome_content = collect_error(welcome(), &mut content_errors).unwrap_or_default();
+        let timeline_content = collect_error(timeline(), &mut content_errors).unwrap_or_default();
+        let contact_content = collect_error(contact(), &mut content_errors).unwrap_or_default();
+        let contact_fields = parse_contact_fields(&contact_content);
+        let screens = match collect_error(load_sections(), &mut content_errors) {
+            Some(Some(sections)) => ScreenRegistry::from_sections(&sections, &mut content_errors),
+            _ => ScreenRegistry::default(),
+        };
         
-        Self {
+        let mut app = Self {
             menu_index: 0,
             link_index: 0,
             skill_category_index: 0,
-            display_mode: DisplayMode::About,
+            display_mode: screens.mode(0),
             previous_mode: DisplayMode::Menu,
             timeline_filter: TimelineFilter::All,
             timeline_event_index: 0,
             timeline_detail_view: false,
-            about_content: about(),
-            skills_content: skills(),
-            skills_data: load_skills_data().unwrap_or_default(),
+            about_content,
+            skills_content,
+            skills_data,
             skills_page: 0,
-            projects_content: projects(),
+            skills_per_page: 5,
+            projects_content,
             project_links,
-            welcome_content: welcome(),
-            timeline_content: timeline(),
-            contact_content: contact(),
-            timeline_events: timeline_events.into_iter().map(|e| TimelineEvent {
-                year: e.year as u16,
-                event_type: match e.year % 5 {
-                    0 => TimelineType::Career,
-                    1 => TimelineType::Education,
-                    2 => TimelineType::Certification,
-                    3 => TimelineType::Project,
-                    _ => TimelineType::Other,
-                },
-                title: e.title,
-                organization: e.organization,
-                description: e.description,
-                highlights: Some(e.highlights),
-                technologies: Some(e.technologies),
-            }).collect(),
+            welcome_content,
+            timeline_content,
+            contact_content,
+            contact_fields,
+            timeline_events,
             timeline_index,
+            content_errors,
+            scroll: HashMap::new(),
+            search_index: SearchIndex::default(),
+            search: SearchState::default(),
+            palette_entries: Vec::new(),
+            palette: PaletteState::default(),
+            help: None,
+            clipboard_request: None,
+            click_targets: Vec::new(),
+            link_focus: None,
+            hyperlinks: false,
+            hyperlink_targets: Vec::new(),
+            url_opener: UrlOpener::default(),
+            themes: Theme::builtin(),
+            theme_index: 0,
+            color_depth: ColorDepth::TrueColor,
+            keymap: Keymap::default(),
+            screens,
+            status: None,
             should_exit: false,
             skip_auto_switch: false,
-        }
+        };
+
+        app.search_index = SearchIndex::build(&app);
+        app.palette_entries = palette_entries(&app);
+        app
     }
     
     pub fn get_filtered_events(&self) -> Vec<&TimelineEvent> {
-        self.timeline_events.iter().collect()
+        self.timeline_events
+            .iter()
+            .filter(|event| self.timeline_filter.matches(event))
+            .collect()
+    }
+
+    /// Returns every filter that matches at least one event: types, then decades, then technologies
+    pub fn available_timeline_filters(&self) -> Vec<TimelineFilter> {
+        let mut filters = vec![TimelineFilter::All];
+
+        for event_type in TimelineType::ALL {
+            if self.timeline_events.iter().any(|event| event.event_type == event_type) {
+                filters.push(TimelineFilter::Type(event_type));
+            }
+        }
+
+        let today = YearMonth::today();
+        let mut decades: Vec<u16> = self.timeline_events
+            .iter()
+            .flat_map(|event| {
+                let (first, last) = event.year_range(today);
+                (first / 10..=last / 10).map(|decade| decade * 10)
+            })
+            .collect();
+        decades.sort_unstable();
+        decades.dedup();
+        filters.extend(decades.into_iter().map(|decade| TimelineFilter::YearRange(decade, decade + 9)));
+
+        let mut technologies: Vec<&String> = self.timeline_events
+            .iter()
+            .flat_map(|event| event.technologies.iter().flatten())
+            .collect();
+        technologies.sort_by_key(|technology| technology.to_lowercase());
+        technologies.dedup_by_key(|technology| technology.to_lowercase());
+        filters.extend(technologies.into_iter().map(|technology| TimelineFilter::Technology(technology.clone())));
+
+        filters
+    }
+
+    /// Moves to the next (or previous) available timeline filter and resets the selection
+    pub fn cycle_timeline_filter(&mut self, forward: bool) {
+        let filters = self.available_timeline_filters();
+        let current = filters.iter().position(|filter| *filter == self.timeline_filter).unwrap_or(0);
+        let next = if forward {
+            (current + 1) % filters.len()
+        } else {
+            (current + filters.len() - 1) % filters.len()
+        };
+
+        self.timeline_filter = filters[next].clone();
+        self.timeline_index = 0;
+        self.timeline_event_index = 0;
+    }
+
+    /// Returns the scrollable pane shown in the current display mode, if any
+    pub fn scroll_target(&self) -> Option<DisplayMode> {
+        if self.display_mode == DisplayMode::Menu {
+            return Some(DisplayMode::About);
+        }
+
+        let mode = self.display_mode;
+        self.screens.screen_for(mode).filter(|screen| screen.scrolls(self)).map(|_| mode)
+    }
+
+    /// Returns the scroll state of a pane
+    pub fn scroll_state(&self, mode: DisplayMode) -> ScrollState {
+        self.scroll.get(&mode).copied().unwrap_or_default()
+    }
+
+    /// Returns the scroll state of a pane for updating
+    pub fn scroll_state_mut(&mut self, mode: DisplayMode) -> &mut ScrollState {
+        self.scroll.entry(mode).or_default()
+    }
+
+    /// Reruns the search for the current query and selects the first match
+    pub fn update_search(&mut self) {
+        self.search.matches = self.search_index.search(&self.search.query);
+        self.search.selected = 0;
+    }
+
+    /// Returns the location of the selected search match, if any
+    pub fn selected_search_location(&self) -> Option<SearchLocation> {
+        self.search
+            .matches
+            .get(self.search.selected)
+            .map(|&entry| self.search_index.entries[entry].location)
+    }
+
+    /// Selects the next (or previous) search match and jumps to it
+    pub fn cycle_search_match(&mut self, forward: bool) {
+        let count = self.search.matches.len();
+        if count == 0 {
+            return;
+        }
+
+        self.search.selected = if forward {
+            (self.search.selected + 1) % count
+        } else {
+            (self.search.selected + count - 1) % count
+        };
+        self.jump_to_selected_match();
+    }
+
+    /// Shows the selected search match, scrolling its pane to the matching line
+    pub fn jump_to_selected_match(&mut self) {
+        let Some(location) = self.selected_search_location() else {
+            return;
+        };
+
+        self.previous_mode = self.display_mode;
+        self.display_mode = location.display_mode();
+        self.menu_index = self.screens.position(self.display_mode).unwrap_or(self.menu_index);
+        self.timeline_detail_view = false;
+
+        match location {
+            SearchLocation::Line(mode, line) => {
+                self.search.pending_scroll = Some((mode, line));
+            }
+            SearchLocation::TimelineEvent(index) => {
+                self.timeline_filter = TimelineFilter::All;
+                self.timeline_index = index;
+                self.timeline_event_index = index;
+                self.timeline_detail_view = true;
+            }
+            SearchLocation::SkillCategory(index) => {
+                self.skill_category_index = index;
+                self.skills_page = 0;
+            }
+            SearchLocation::ProjectLink(index) => {
+                self.link_index = index;
+            }
+        }
+    }
+
+    /// Opens the command palette with `query` already typed
+    pub fn open_palette(&mut self, query: &str) {
+        self.palette.open = true;
+        self.palette.query = query.to_string();
+        self.update_palette();
+    }
+
+    /// Refilters the palette entries for the current query and selects the best match
+    pub fn update_palette(&mut self) {
+        self.palette.matches = filter_entries(&self.palette_entries, &self.palette.query);
+        self.palette.selected = 0;
+    }
+
+    /// Adds a screen to the end of the menu and the command palette, returning the display mode it opens in
+    pub fn register_screen(&mut self, screen: impl Screen + 'static) -> DisplayMode {
+        let mode = self.screens.register(screen);
+        self.palette_entries = palette_entries(self);
+        mode
+    }
+
+    /// Returns the keymap scope of the current screen
+    pub fn key_scope(&self) -> Scope {
+        if self.display_mode == DisplayMode::Menu {
+            return Scope::Menu;
+        }
+
+        self.screens.screen_for(self.display_mode).map_or(Scope::Content, |screen| screen.scope(self))
+    }
+
+    /// Returns the theme the interface is drawn with
+    pub fn theme(&self) -> &Theme {
+        &self.themes[self.theme_index.min(self.themes.len() - 1)]
+    }
+
+    /// Returns the theme as drawn on this terminal, relying on modifiers when it has no color
+    pub fn display_theme(&self) -> Theme {
+        let mut theme = self.theme().clone();
+        theme.monochrome |= self.color_depth == ColorDepth::Monochrome;
+        theme
+    }
+
+    /// Switches to the next theme, wrapping around, and names it in the footer
+    pub fn cycle_theme(&mut self) {
+        self.theme_index = (self.theme_index + 1) % self.themes.len();
+        self.set_status(format!("Theme: {}", self.theme().name), false);
+    }
+
+    /// Shows a message in the footer until it expires
+    pub fn set_status(&mut self, text: impl Into<String>, is_error: bool) {
+        self.status = Some(StatusMessage { text: text.into(), is_error, shown_at: Instant::now() });
+    }
+
+    /// Clears the status message once it has been shown long enough
+    pub fn expire_status(&mut self, now: Instant) {
+        if self.status.as_ref().is_some_and(|status| status.is_expired(now)) {
+            self.status = None;
+        }
+    }
+
+    /// Opens a URL with the configured opener, copying it to the clipboard and reporting it if that fails
+    pub fn open_url(&mut self, url: &str) {
+        match self.url_opener.open(url) {
+            Ok(program) => self.set_status(format!("Opened {} with {}", url, program), false),
+            Err(error) => {
+                self.clipboard_request = Some(url.to_string());
+                self.set_status(format!("Couldn't open {} ({}). Copying the URL to the clipboard instead.", url, error), true);
+            }
+        }
+    }
+
+    /// Asks the runner to copy text to the clipboard, which reports the outcome through [`App::finish_copy`]
+    pub fn copy_to_clipboard(&mut self, text: &str) {
+        self.clipboard_request = Some(text.to_string());
+        self.set_status(format!("Copying {} to the clipboard…", text), false);
+    }
+
+    /// Shows whether copying text to the clipboard worked
+    pub fn finish_copy(&mut self, text: &str, result: Result<String, String>) {
+        match result {
+            Ok(_) => self.set_status(format!("Copied {} to the clipboard", text), false),
+            Err(error) => self.set_status(format!("Couldn't copy {} to the clipboard ({})", text, error), true),
+        }
+    }
+
+    /// Copies the focused link, the selected project link, or a contact field picked from the palette
+    pub fn yank(&mut self) {
+        if let Some(link) = self.focused_link() {
+            self.copy_to_clipboard(&link.url);
+        } else if self.display_mode == DisplayMode::ProjectLinks {
+            if let Some(url) = self.project_links.get(self.link_index).map(|link| link.url.clone()) {
+                self.copy_to_clipboard(&url);
+            }
+        } else if self.display_mode == DisplayMode::Contact {
+            self.open_palette("copy ");
+        } else {
+            let message = match self.keymap.key_name(self.key_scope(), Action::NextLink) {
+                Some(key) => format!("Nothing to copy here. Press {} to focus a link first.", key),
+                None => String::from("Nothing to copy here."),
+            };
+            self.set_status(message, true);
+        }
+    }
+
+    /// Returns the markdown a display mode renders, if it renders any
+    pub fn markdown_content(&self, mode: DisplayMode) -> Option<&str> {
+        match mode {
+            DisplayMode::Menu | DisplayMode::About => Some(&self.about_content),
+            DisplayMode::Skills => Some(&self.skills_content),
+            DisplayMode::Projects => Some(&self.projects_content),
+            DisplayMode::Timeline => Some(&self.timeline_content),
+            DisplayMode::Contact => Some(&self.contact_content),
+            DisplayMode::Custom(_) => self.screens.markdown(mode),
+            DisplayMode::SkillsVisual | DisplayMode::ProjectLinks => None,
+        }
+    }
+
+    /// Returns the inline links of a section's markdown
+    pub fn section_links(&self, mode: DisplayMode) -> Vec<Link> {
+        self.markdown_content(mode).map(|content| parse_markdown(content).1).unwrap_or_default()
+    }
+
+    /// Returns the inline link focused in the current pane, if any
+    pub fn focused_link(&self) -> Option<Link> {
+        let focus = self.link_focus?;
+        if Some(focus.mode) != self.scroll_target() {
+            return None;
+        }
+        self.section_links(focus.mode).into_iter().nth(focus.index)
+    }
+
+    /// Moves link focus to the next (or previous) inline link of the current pane, wrapping around
+    pub fn cycle_link_focus(&mut self, forward: bool) {
+        let Some(mode) = self.scroll_target() else {
+            return;
+        };
+        let count = self.section_links(mode).len();
+        if count == 0 {
+            return;
+        }
+
+        let current = self.link_focus.filter(|focus| focus.mode == mode).map(|focus| focus.index);
+        let index = match (current, forward) {
+            (Some(index), true) => (index + 1) % count,
+            (Some(index), false) => (index + count - 1) % count,
+            (None, true) => 0,
+            (None, false) => count - 1,
+        };
+        self.link_focus = Some(LinkFocus { mode, index, reveal: true });
+    }
+
+    /// Returns the topmost click target rendered at a screen cell
+    pub fn click_target_at(&self, column: u16, row: u16) -> Option<ClickTarget> {
+        self.click_targets
+            .iter()
+            .rev()
+            .find(|(area, _)| area.contains(Position::new(column, row)))
+            .map(|(_, target)| *target)
+    }
+
+    /// Returns the first load error for any of the given content files
+    pub fn content_error(&self, files: &[&str]) -> Option<&ContentError> {
+        self.content_errors.iter().find(|error| files.contains(&error.file()))
     }
 }
 
-fn load_skills_data() -> Result<SkillsData, Box<dyn Error>> {
-    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
-        .join("src")
-        .join("static")
-        .join("content")
-        .join("skills.json");
-    
-    let content = std::fs::read_to_string(&path)?;
-    let skills_data: SkillsData = serde_json::from_str(&content)?;
-    Ok(skills_data)
+fn collect_error<T>(result: Result<T, ContentError>, errors: &mut Vec<ContentError>) -> Option<T> {
+    result.map_err(|error| errors.push(error)).ok()
 }
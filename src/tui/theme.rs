@@ -0,0 +1,493 @@
+use ratatui::style::{Color, Modifier, Style};
+use serde::Deserialize;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use super::models::TimelineType;
+use crate::content::xdg_config_dir;
+
+/// Environment variable naming the theme, or theme file, to start with
+pub const THEME_ENV: &str = "HIRE_DAVID_PARKER_THEME";
+
+/// The colors the interface is drawn with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    pub name: String,
+    /// Borders of panes and blocks
+    pub border: Color,
+    /// The title bar and section headings
+    pub title: Color,
+    /// Selected items and focused links
+    pub highlight: Color,
+    /// Text drawn on top of the highlight color
+    pub highlight_text: Color,
+    /// Links in markdown and project lists
+    pub link: Color,
+    /// Skill gauges and technology lists
+    pub gauge: Color,
+    /// Hints, indicators, and other secondary text
+    pub muted: Color,
+    /// Body text outside the markdown panes
+    pub text: Color,
+    /// Errors
+    pub error: Color,
+    /// Confirmations
+    pub success: Color,
+    /// Career events on the timeline
+    pub timeline_career: Color,
+    /// Education events on the timeline
+    pub timeline_education: Color,
+    /// Certification events on the timeline
+    pub timeline_certification: Color,
+    /// Project events on the timeline
+    pub timeline_project: Color,
+    /// Timeline events of any other type
+    pub timeline_other: Color,
+    /// Marks selections and links with modifiers alone, for terminals without color
+    pub monochrome: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// The original palette, for dark terminal backgrounds
+    pub fn dark() -> Self {
+        Self {
+            name: String::from("dark"),
+            border: Color::Blue,
+            title: Color::Cyan,
+            highlight: Color::Yellow,
+            highlight_text: Color::Black,
+            link: Color::Cyan,
+            gauge: Color::Green,
+            muted: Color::DarkGray,
+            text: Color::Gray,
+            error: Color::Red,
+            success: Color::Green,
+            timeline_career: Color::Cyan,
+            timeline_education: Color::Magenta,
+            timeline_certification: Color::Green,
+            timeline_project: Color::LightBlue,
+            timeline_other: Color::White,
+            monochrome: false,
+        }
+    }
+
+    /// A palette for light terminal backgrounds
+    pub fn light() -> Self {
+        Self {
+            name: String::from("light"),
+            border: Color::Blue,
+            title: Color::Blue,
+            highlight: Color::Magenta,
+            highlight_text: Color::White,
+            link: Color::Blue,
+            gauge: Color::Green,
+            muted: Color::Gray,
+            text: Color::Black,
+            error: Color::Red,
+            success: Color::Green,
+            timeline_career: Color::Blue,
+            timeline_education: Color::Magenta,
+            timeline_certification: Color::Rgb(0x00, 0x80, 0x00),
+            timeline_project: Color::Rgb(0x00, 0x80, 0x80),
+            timeline_other: Color::DarkGray,
+            monochrome: false,
+        }
+    }
+
+    /// The Solarized dark palette
+    pub fn solarized() -> Self {
+        Self {
+            name: String::from("solarized"),
+            border: Color::Rgb(0x26, 0x8b, 0xd2),
+            title: Color::Rgb(0x2a, 0xa1, 0x98),
+            highlight: Color::Rgb(0xb5, 0x89, 0x00),
+            highlight_text: Color::Rgb(0x00, 0x2b, 0x36),
+            link: Color::Rgb(0x6c, 0x71, 0xc4),
+            gauge: Color::Rgb(0x85, 0x99, 0x00),
+            muted: Color::Rgb(0x58, 0x6e, 0x75),
+            text: Color::Rgb(0x83, 0x94, 0x96),
+            error: Color::Rgb(0xdc, 0x32, 0x2f),
+            success: Color::Rgb(0x85, 0x99, 0x00),
+            timeline_career: Color::Rgb(0x2a, 0xa1, 0x98),
+            timeline_education: Color::Rgb(0xd3, 0x36, 0x82),
+            timeline_certification: Color::Rgb(0x85, 0x99, 0x00),
+            timeline_project: Color::Rgb(0x26, 0x8b, 0xd2),
+            timeline_other: Color::Rgb(0x93, 0xa1, 0xa1),
+            monochrome: false,
+        }
+    }
+
+    /// Bright colors only, for low vision or washed out displays
+    pub fn high_contrast() -> Self {
+        Self {
+            name: String::from("high-contrast"),
+            border: Color::White,
+            title: Color::White,
+            highlight: Color::LightYellow,
+            highlight_text: Color::Black,
+            link: Color::LightCyan,
+            gauge: Color::LightGreen,
+            muted: Color::Gray,
+            text: Color::White,
+            error: Color::LightRed,
+            success: Color::LightGreen,
+            timeline_career: Color::LightCyan,
+            timeline_education: Color::LightMagenta,
+            timeline_certification: Color::LightGreen,
+            timeline_project: Color::LightBlue,
+            timeline_other: Color::White,
+            monochrome: false,
+        }
+    }
+
+    /// Returns the themes that ship with the binary
+    pub fn builtin() -> Vec<Theme> {
+        vec![Self::dark(), Self::light(), Self::solarized(), Self::high_contrast()]
+    }
+
+    /// Parses a TOML theme, starting from its `base` built-in theme, or `dark`, for colors it leaves out
+    pub fn from_toml(name: &str, source: &str) -> Result<Theme, ThemeError> {
+        let file: ThemeFile = toml::from_str(source).map_err(|e| ThemeError::parse(name, e.message()))?;
+
+        let base = file.base.as_deref().unwrap_or("dark");
+        let mut theme = Self::builtin()
+            .into_iter()
+            .find(|theme| theme.name.eq_ignore_ascii_case(base))
+            .ok_or_else(|| ThemeError::parse(name, format!("unknown base theme '{}'", base)))?;
+        theme.name = file.name.clone().unwrap_or_else(|| name.to_string());
+        theme.monochrome = file.monochrome.unwrap_or(theme.monochrome);
+
+        for (field, value, color) in [
+            ("border", &file.border, &mut theme.border),
+            ("title", &file.title, &mut theme.title),
+            ("highlight", &file.highlight, &mut theme.highlight),
+            ("highlight_text", &file.highlight_text, &mut theme.highlight_text),
+            ("link", &file.link, &mut theme.link),
+            ("gauge", &file.gauge, &mut theme.gauge),
+            ("muted", &file.muted, &mut theme.muted),
+            ("text", &file.text, &mut theme.text),
+            ("error", &file.error, &mut theme.error),
+            ("success", &file.success, &mut theme.success),
+            ("timeline_career", &file.timeline_career, &mut theme.timeline_career),
+            ("timeline_education", &file.timeline_education, &mut theme.timeline_education),
+            ("timeline_certification", &file.timeline_certification, &mut theme.timeline_certification),
+            ("timeline_project", &file.timeline_project, &mut theme.timeline_project),
+            ("timeline_other", &file.timeline_other, &mut theme.timeline_other),
+        ] {
+            if let Some(value) = value {
+                *color = Color::from_str(value)
+                    .map_err(|_| ThemeError::parse(name, format!("unknown color '{}' for {}", value, field)))?;
+            }
+        }
+
+        Ok(theme)
+    }
+
+    /// Reads a TOML theme file, named after the file unless it sets a `name`
+    pub fn load(path: &Path) -> Result<Theme, ThemeError> {
+        let file = path.display().to_string();
+        let source = fs::read_to_string(path).map_err(|source| ThemeError::Io { file: file.clone(), source })?;
+        let name = path.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or(file);
+        Self::from_toml(&name, &source)
+    }
+
+    /// Style of the selected item of a list
+    pub fn selected(&self) -> Style {
+        let style = Style::default().fg(self.highlight).add_modifier(Modifier::BOLD);
+        if self.monochrome {
+            style.add_modifier(Modifier::REVERSED)
+        } else {
+            style
+        }
+    }
+
+    /// Returns the color of timeline events of the given type
+    pub fn timeline_color(&self, event_type: TimelineType) -> Color {
+        match event_type {
+            TimelineType::Career => self.timeline_career,
+            TimelineType::Education => self.timeline_education,
+            TimelineType::Certification => self.timeline_certification,
+            TimelineType::Project => self.timeline_project,
+            TimelineType::Other => self.timeline_other,
+        }
+    }
+
+    /// Style of links that aren't focused
+    pub fn link_style(&self) -> Style {
+        Style::default().fg(self.link).add_modifier(Modifier::UNDERLINED)
+    }
+
+    /// Style of the focused link and the selected search match
+    pub fn focused(&self) -> Style {
+        if self.monochrome {
+            Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD | Modifier::UNDERLINED)
+        } else {
+            Style::default().fg(self.highlight_text).bg(self.highlight).add_modifier(Modifier::BOLD)
+        }
+    }
+
+    /// Style of search matches other than the selected one
+    pub fn matched(&self) -> Style {
+        if self.monochrome {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default().fg(self.highlight_text).bg(self.muted)
+        }
+    }
+}
+
+/// How many colors the terminal can show
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorDepth {
+    /// No color at all, because of `NO_COLOR` or `TERM=dumb`
+    Monochrome,
+    /// The 16 ANSI colors
+    Ansi16,
+    /// The 256 color xterm palette
+    Ansi256,
+    /// 24-bit RGB colors
+    TrueColor,
+}
+
+/// The 16 ANSI colors with the RGB values xterm gives them
+const ANSI_COLORS: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Channel values of the 6x6x6 color cube in the 256 color palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl ColorDepth {
+    /// Detects the color depth of the current terminal
+    pub fn detect() -> Self {
+        Self::from_env(|name| env::var(name).ok())
+    }
+
+    /// Decides the color depth from the environment variables returned by `var`
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
+        if var("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            return ColorDepth::Monochrome;
+        }
+
+        let term = var("TERM").unwrap_or_default();
+        if term == "dumb" {
+            return ColorDepth::Monochrome;
+        }
+
+        let colorterm = var("COLORTERM").unwrap_or_default().to_ascii_lowercase();
+        if colorterm == "truecolor" || colorterm == "24bit" || term.ends_with("-direct") || var("WT_SESSION").is_some() {
+            ColorDepth::TrueColor
+        } else if term.contains("256color") {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+
+    /// Returns the closest color the terminal can show, or `Color::Reset` without color
+    pub fn adapt(self, color: Color) -> Color {
+        match (self, color) {
+            (_, Color::Reset) => Color::Reset,
+            (ColorDepth::Monochrome, _) => Color::Reset,
+            (ColorDepth::TrueColor, color) => color,
+            (ColorDepth::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(nearest_indexed(r, g, b)),
+            (ColorDepth::Ansi256, color) => color,
+            (ColorDepth::Ansi16, Color::Rgb(r, g, b)) => nearest_ansi(r, g, b),
+            (ColorDepth::Ansi16, Color::Indexed(index)) => {
+                let (r, g, b) = indexed_rgb(index);
+                nearest_ansi(r, g, b)
+            }
+            (ColorDepth::Ansi16, color) => color,
+        }
+    }
+}
+
+/// Returns the RGB value of a 256 color palette index
+fn indexed_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI_COLORS[usize::from(index)].1,
+        16..=231 => {
+            let index = index - 16;
+            let level = |i: u8| CUBE_LEVELS[usize::from(i)];
+            (level(index / 36), level(index / 6 % 6), level(index % 6))
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+/// Returns the 256 color palette index closest to an RGB color, from the color cube or the gray ramp
+fn nearest_indexed(r: u8, g: u8, b: u8) -> u8 {
+    let level = |value: u8| (0..6u8).min_by_key(|&i| CUBE_LEVELS[usize::from(i)].abs_diff(value)).unwrap_or(0);
+    let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+    [cube, gray]
+        .into_iter()
+        .min_by_key(|&index| distance((r, g, b), indexed_rgb(index)))
+        .unwrap_or(cube)
+}
+
+/// Returns the ANSI color closest to an RGB color
+fn nearest_ansi(r: u8, g: u8, b: u8) -> Color {
+    ANSI_COLORS
+        .iter()
+        .min_by_key(|(_, rgb)| distance((r, g, b), *rgb))
+        .map_or(Color::Reset, |(color, _)| *color)
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let channel = |x: u8, y: u8| u32::from(x.abs_diff(y)).pow(2);
+    channel(a.0, b.0) + channel(a.1, b.1) + channel(a.2, b.2)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    name: Option<String>,
+    base: Option<String>,
+    border: Option<String>,
+    title: Option<String>,
+    highlight: Option<String>,
+    highlight_text: Option<String>,
+    link: Option<String>,
+    gauge: Option<String>,
+    muted: Option<String>,
+    text: Option<String>,
+    error: Option<String>,
+    success: Option<String>,
+    timeline_career: Option<String>,
+    timeline_education: Option<String>,
+    timeline_certification: Option<String>,
+    timeline_project: Option<String>,
+    timeline_other: Option<String>,
+    monochrome: Option<bool>,
+}
+
+/// Errors raised while loading or choosing a theme
+#[derive(Debug)]
+pub enum ThemeError {
+    /// The theme file could not be read
+    Io { file: String, source: io::Error },
+    /// The theme file is not a valid theme
+    Parse { theme: String, message: String },
+    /// No theme has the requested name
+    Unknown { name: String, available: Vec<String> },
+}
+
+impl ThemeError {
+    fn parse(theme: &str, message: impl Into<String>) -> Self {
+        ThemeError::Parse { theme: theme.to_string(), message: message.into() }
+    }
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeError::Io { file, source } => write!(f, "failed to read theme '{}': {}", file, source),
+            ThemeError::Parse { theme, message } => write!(f, "invalid theme '{}': {}", theme, message),
+            ThemeError::Unknown { name, available } => {
+                write!(f, "unknown theme '{}', expected one of: {}", name, available.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ThemeError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the XDG config location for user themes, e.g. `~/.config/hire-david-parker/themes`
+pub fn xdg_theme_dir() -> Option<PathBuf> {
+    xdg_config_dir().map(|dir| dir.join("themes"))
+}
+
+/// Loads the built-in themes followed by every `.toml` theme in `dir`, returning the files that failed to load
+pub fn load_themes(dir: Option<&Path>) -> (Vec<Theme>, Vec<ThemeError>) {
+    let mut themes = Theme::builtin();
+    let mut errors = Vec::new();
+
+    let mut paths: Vec<PathBuf> = dir
+        .and_then(|dir| fs::read_dir(dir).ok())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "toml"))
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        match Theme::load(&path) {
+            Ok(theme) => {
+                add_theme(&mut themes, theme);
+            }
+            Err(e) => errors.push(e),
+        }
+    }
+
+    (themes, errors)
+}
+
+/// Picks a theme by name, or loads it from a `.toml` file, returning its index in `themes`
+pub fn select_theme(themes: &mut Vec<Theme>, name: &str) -> Result<usize, ThemeError> {
+    let path = Path::new(name);
+    if path.extension().is_some_and(|extension| extension == "toml") || path.is_file() {
+        return Ok(add_theme(themes, Theme::load(path)?));
+    }
+
+    themes
+        .iter()
+        .position(|theme| theme.name.eq_ignore_ascii_case(name.trim()))
+        .ok_or_else(|| ThemeError::Unknown {
+            name: name.to_string(),
+            available: themes.iter().map(|theme| theme.name.clone()).collect(),
+        })
+}
+
+/// Adds a theme, replacing one with the same name, and returns its index
+fn add_theme(themes: &mut Vec<Theme>, theme: Theme) -> usize {
+    match themes.iter().position(|existing| existing.name.eq_ignore_ascii_case(&theme.name)) {
+        Some(index) => {
+            themes[index] = theme;
+            index
+        }
+        None => {
+            themes.push(theme);
+            themes.len() - 1
+        }
+    }
+}
@@ -1,17 +1,43 @@
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Alignment, Rect},
-    style::{Color, Modifier, Style},
-    text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap, Gauge},
+    layout::{Constraint, Direction, Layout, Alignment, Margin, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap, Gauge},
     Frame,
 };
 
+use super::keymap::{Action, Hint, Scope};
 use super::state::App;
-use super::models::DisplayMode;
-use super::markdown::parse_markdown;
+use super::theme::{ColorDepth, Theme};
+use crate::ContentError;
+use super::models::{format_duration, timeline_span_lanes, ClickTarget, DisplayMode, Link, TimelineType, YearMonth};
+use super::markdown::{parse_markdown, style_columns, wrapped_position};
+use super::search::{highlight_matches, SearchLocation};
+use unicode_width::UnicodeWidthStr;
+
+/// Returns the footer hints for the current screen
+fn footer_hints(app: &App) -> Vec<Hint> {
+    match app.screens.screen_for(app.display_mode) {
+        Some(screen) => screen.footer_hints(app),
+        None => vec![
+            Hint::QUIT,
+            Hint::HELP,
+            Hint::UP,
+            Hint::DOWN,
+            Hint::SELECT,
+            Hint::new("Search", &[Action::Search]),
+            Hint::new("Commands", &[Action::CommandPalette]),
+            Hint::new("Theme", &[Action::CycleTheme]),
+        ],
+    }
+}
 
 /// Renders the user interface widgets
 pub fn render(f: &mut Frame, app: &mut App) {
+    app.click_targets.clear();
+    app.hyperlink_targets.clear();
+    let theme = app.display_theme();
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints(
@@ -27,247 +53,392 @@ pub fn render(f: &mut Frame, app: &mut App) {
     let title = Paragraph::new("David Parker - Interactive Resume")
         .style(Style::default().add_modifier(Modifier::BOLD))
         .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan)));
+        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
     f.render_widget(title, chunks[0]);
 
-    let footer_text = match app.display_mode {
-        DisplayMode::Menu => "q: Quit | ↑/k: Up | ↓/j: Down | Enter: Select",
-        DisplayMode::Timeline => "q: Quit | ←/h: Previous | →/l: Next | Esc: Return to Menu",
-        DisplayMode::SkillsVisual => {
-            let has_multiple_pages = if !app.skills_data.categories.is_empty() {
-                let category_index = app.skill_category_index.min(app.skills_data.categories.len() - 1);
-                let category = &app.skills_data.categories[category_index];
-                
-                let skills_per_page = 5;
-                let total_skills = category.skills.len();
-                
-                total_skills > skills_per_page
-            } else {
-                false
-            };
-            
-            if has_multiple_pages {
-                "q: Quit | ↑/↓: Categories | ←/→: Pages | Esc: Return to Menu"
-            } else {
-                "q: Quit | ↑/↓: Categories | Esc: Return to Menu"
-            }
-        },
-        _ => "q: Quit | ↑/k: Up | ↓/j: Down | Enter: Select | Esc: Return to Menu",
-    };
-    let footer = Paragraph::new(footer_text)
-        .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::DarkGray)));
-    f.render_widget(footer, chunks[2]);
-
     let content_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(25), Constraint::Percentage(75)].as_ref())
         .split(chunks[1]);
     
     render_menu_sidebar(f, app, content_chunks[0]);
-    
-    match app.display_mode {
-        DisplayMode::Menu => {
-            render_about(f, app, content_chunks[1])
-        },
-        DisplayMode::About => render_about(f, app, content_chunks[1]),
-        DisplayMode::Skills => render_skills(f, app, content_chunks[1]),
-        DisplayMode::SkillsVisual => render_skills_visual(f, app, content_chunks[1]),
-        DisplayMode::Projects => render_projects(f, app, content_chunks[1]),
-        DisplayMode::ProjectLinks => render_project_links(f, app, content_chunks[1]),
-        DisplayMode::Timeline => render_timeline(f, app, content_chunks[1]),
-        DisplayMode::Contact => render_contact(f, app, content_chunks[1]),
+
+    // The menu previews the About section
+    let screen = app.screens.screen_for(app.display_mode);
+    let files = screen.as_ref().map_or_else(|| vec!["about.md"], |screen| screen.content_files(app));
+    if let Some(error) = app.content_error(&files) {
+        render_content_error(f, &theme, error, content_chunks[1]);
+    } else {
+        match screen.as_ref() {
+            Some(screen) => screen.render(f, app, content_chunks[1]),
+            None => render_about(f, app, content_chunks[1]),
+        }
+    }
+
+    // The footer follows the content, whose layout decides hints such as the skill pages
+    let footer_text = app.keymap.footer(app.key_scope(), &footer_hints(app));
+    let footer_text = if app.palette.open {
+        String::from("Type to filter | ↑/↓: Select | Enter: Run | Esc: Close")
+    } else if app.help.is_some() {
+        app.keymap.footer(app.key_scope(), &[
+            Hint::new("Scroll", &[Action::Up, Action::Down]),
+            Hint::new("Close", &[Action::Back, Action::Help]),
+        ])
+    } else if app.search.prompt_open {
+        String::from("Type to search | ↑/↓: Select | Enter: Jump | Esc: Cancel")
+    } else if app.search.matches.is_empty() {
+        footer_text
+    } else {
+        let matches = Hint::new("Match", &[Action::NextMatch, Action::PreviousMatch]);
+        let keys = app.keymap.hint(app.key_scope(), &matches).unwrap_or_else(|| matches.label.to_string());
+        format!("{} {}/{} | {}", keys, app.search.selected + 1, app.search.matches.len(), footer_text)
+    };
+    let mut footer_block = Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.muted));
+    if let Some(status) = &app.status {
+        let color = if status.is_error { theme.error } else { theme.success };
+        footer_block = footer_block.title(Line::styled(
+            format!(" {} ", status.text),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ));
+    }
+    let footer = Paragraph::new(footer_text)
+        .alignment(Alignment::Center)
+        .block(footer_block);
+    f.render_widget(footer, chunks[2]);
+
+    if app.search.prompt_open || app.palette.open || app.help.is_some() {
+        app.hyperlink_targets.clear();
+    }
+
+    if app.search.prompt_open {
+        render_search(f, app, &theme, content_chunks[1]);
+    }
+
+    if app.palette.open {
+        render_palette(f, app, &theme);
+    }
+
+    if app.help.is_some() {
+        render_help(f, app, &theme);
+    }
+
+    if app.color_depth != ColorDepth::TrueColor {
+        for cell in &mut f.buffer_mut().content {
+            cell.fg = app.color_depth.adapt(cell.fg);
+            cell.bg = app.color_depth.adapt(cell.bg);
+        }
     }
 }
 
-/// Renders the menu sidebar (always visible)
-fn render_menu_sidebar(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
-    let menu_items = vec![
-        "About Me",
-        "Skills",
-        "Projects",
-        "Timeline",
-        "Contact",
-    ];
+/// Renders the key bindings of the current screen, grouped by category, centered over the whole screen
+fn render_help(f: &mut Frame, app: &mut App, theme: &Theme) {
+    let screen = f.area();
+    let width = (screen.width * 3 / 5).max(50).min(screen.width);
+    let height = (screen.height * 4 / 5).max(10).min(screen.height);
+    let area = Rect::new(
+        screen.x + (screen.width - width) / 2,
+        screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    );
+
+    let screen = match app.key_scope() {
+        Scope::Menu => String::from("Menu"),
+        Scope::ProjectLinks => String::from("Project Links"),
+        Scope::TimelineDetail => String::from("Timeline Details"),
+        _ => app.screens.screen_for(app.display_mode).map(|screen| screen.title().to_string()).unwrap_or_default(),
+    };
+    let sections = app.help_sections();
+    let key_width = sections
+        .iter()
+        .flat_map(|(_, entries)| entries.iter().map(|(keys, _)| keys.width()))
+        .max()
+        .unwrap_or(0);
+
+    let mut lines = Vec::new();
+    for (category, entries) in sections {
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::styled(category.label(), Style::default().fg(theme.title).add_modifier(Modifier::BOLD)));
+        for (keys, description) in entries {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<width$}", keys, width = key_width), Style::default().fg(theme.highlight)),
+                Span::raw("  "),
+                Span::styled(description, Style::default().fg(theme.text)),
+            ]));
+        }
+    }
+
+    let block = Block::default()
+        .title(format!("Help: {}", screen))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.highlight));
+    let inner_area = block.inner(area);
+    let Some(help) = app.help.as_mut() else {
+        return;
+    };
+    help.set_dimensions(lines.len() as u16, inner_area.height);
+
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).scroll((help.offset, 0)).block(block), area);
+}
+
+/// Renders the command palette centered over the whole screen
+fn render_palette(f: &mut Frame, app: &App, theme: &Theme) {
+    let screen = f.area();
+    let width = (screen.width * 3 / 5).max(40).min(screen.width);
+    let height = (screen.height * 3 / 5).max(8).min(screen.height);
+    let area = Rect::new(
+        screen.x + (screen.width - width) / 2,
+        screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    );
+
+    let block = Block::default()
+        .title("Command Palette")
+        .title_bottom(Line::styled(
+            format!(" {}/{} ", app.palette.matches.len(), app.palette_entries.len()),
+            Style::default().fg(theme.muted),
+        ).right_aligned())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.highlight));
+    let inner_area = block.inner(area);
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0)].as_ref())
+        .split(inner_area);
 
-    let items: Vec<ListItem> = menu_items
+    let prompt = Paragraph::new(Line::from(vec![
+        Span::styled("> ", Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)),
+        Span::raw(app.palette.query.as_str()),
+    ]))
+    .block(Block::default().borders(Borders::BOTTOM).border_style(Style::default().fg(theme.muted)));
+    f.render_widget(prompt, chunks[0]);
+
+    let items: Vec<ListItem> = app
+        .palette
+        .matches
         .iter()
+        .map(|&entry| ListItem::new(app.palette_entries[entry].label.as_str()))
+        .collect();
+    let list = List::new(items)
+        .highlight_style(theme.selected())
+        .highlight_symbol("› ");
+    let selected = (!app.palette.matches.is_empty()).then_some(app.palette.selected);
+    let mut state = ListState::default().with_selected(selected);
+    f.render_stateful_widget(list, chunks[1], &mut state);
+}
+
+/// Renders the search prompt over the content with matches grouped by section
+fn render_search(f: &mut Frame, app: &App, theme: &Theme, area: Rect) {
+    let mut items = Vec::new();
+    let mut selected_item = None;
+    let mut section = String::new();
+
+    for (i, &entry) in app.search.matches.iter().enumerate() {
+        let entry = &app.search_index.entries[entry];
+        let entry_section = match entry.location.display_mode() {
+            mode @ DisplayMode::Custom(_) => app.screens.screen_for(mode).map(|screen| screen.title().to_string()).unwrap_or_default(),
+            _ => entry.location.section().to_string(),
+        };
+        if entry_section != section {
+            section = entry_section;
+            items.push(ListItem::new(Line::from(Span::styled(
+                section.clone(),
+                Style::default().fg(theme.title).add_modifier(Modifier::BOLD),
+            ))));
+        }
+
+        let mut line = Line::from(format!("  {}", entry.label));
+        highlight_matches(&mut line, &app.search.query, Style::default().fg(theme.highlight));
+        if i == app.search.selected {
+            selected_item = Some(items.len());
+        }
+        items.push(ListItem::new(line));
+    }
+
+    let count = match app.search.matches.len() {
+        0 if app.search.query.is_empty() => String::from("Type to search"),
+        0 => String::from("No matches"),
+        1 => String::from("1 match"),
+        n => format!("{} matches", n),
+    };
+
+    let block = Block::default()
+        .title(format!("Search: /{}", app.search.query))
+        .title_bottom(Line::styled(format!(" {} ", count), Style::default().fg(theme.muted)).right_aligned())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.highlight));
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(if theme.monochrome { theme.selected() } else { Style::default().bg(theme.muted).add_modifier(Modifier::BOLD) });
+    let mut state = ListState::default().with_selected(selected_item);
+
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+/// Renders a panel explaining why the current section's content failed to load
+pub(crate) fn render_content_error(f: &mut Frame, theme: &Theme, error: &ContentError, area: Rect) {
+    let text = vec![
+        Line::from(Span::styled(
+            "This section could not be loaded.",
+            Style::default().fg(theme.error).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(error.to_string()),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Check the file in your content directory, or remove it to use the built-in copy.",
+            Style::default().fg(theme.muted),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(text)
+        .block(Block::default().title("Content Error").borders(Borders::ALL).border_style(Style::default().fg(theme.error)))
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(paragraph, area);
+}
+
+/// Renders the menu sidebar (always visible)
+fn render_menu_sidebar(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
+    let theme = app.display_theme();
+    let entries = app.screens.entries();
+    let items: Vec<ListItem> = entries
+        .into_iter()
+        .map(|(title, _)| title)
         .enumerate()
         .map(|(i, item)| {
             let style = if i == app.menu_index {
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                theme.selected()
             } else {
                 Style::default()
             };
-            ListItem::new(Line::from(Span::styled(*item, style)))
+            ListItem::new(Line::from(Span::styled(item, style)))
         })
         .collect();
 
+    let block = Block::default().title("Menu").borders(Borders::ALL).border_style(Style::default().fg(theme.border));
+    push_list_click_targets(app, block.inner(area), app.screens.len(), ClickTarget::MenuItem);
+
     let menu = List::new(items)
-        .block(Block::default().title("Menu").borders(Borders::ALL).border_style(Style::default().fg(Color::Blue)))
+        .block(block)
         .highlight_style(Style::default().add_modifier(Modifier::BOLD));
 
     f.render_widget(menu, area);
 }
 
-/// Renders the about section
-fn render_about(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
-    let (mut text, _links) = parse_markdown(&app.about_content);
-    
-    for line in &mut text.lines {
-        let line_content = line.spans.iter()
-            .map(|span| span.content.to_string())
-            .collect::<String>();
-        
-        if line_content.starts_with("->") && line_content.ends_with("<-") {
-            *line = line.clone().alignment(ratatui::layout::Alignment::Center);
-            
-            if !line.spans.is_empty() {
-                if let Some(first_span) = line.spans.first_mut() {
-                    if first_span.content.starts_with("->") {
-                        let new_content = first_span.content.to_string();
-                        first_span.content = new_content[2..].to_string().into();
-                    }
-                }
-                
-                if let Some(last_span) = line.spans.last_mut() {
-                    if last_span.content.ends_with("<-") {
-                        let new_content = last_span.content.to_string();
-                        last_span.content = new_content[..new_content.len()-2].to_string().into();
-                    }
-                }
-            }
-        }
+/// Records one click target per row for the items of a list rendered in `area`
+fn push_list_click_targets(app: &mut App, area: Rect, count: usize, target: fn(usize) -> ClickTarget) {
+    for (i, y) in (area.top()..area.bottom()).take(count).enumerate() {
+        app.click_targets.push((Rect::new(area.x, y, area.width, 1), target(i)));
     }
-    
-    let paragraph = Paragraph::new(text)
-        .block(Block::default().title("About Me").borders(Borders::ALL).border_style(Style::default().fg(Color::Blue)))
-        .wrap(Wrap { trim: true });
-    
-    f.render_widget(paragraph, area);
+}
+
+/// Renders the about section
+pub(crate) fn render_about(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
+    let (text, links) = parse_markdown(&app.about_content);
+    render_scrollable_text(f, app, DisplayMode::About, text, &links, "About Me", area);
+}
+
+/// Renders a markdown section declared in the content manifest
+pub(crate) fn render_section(f: &mut Frame, app: &mut App, mode: DisplayMode, title: &str, area: Rect) {
+    let (text, links) = parse_markdown(app.markdown_content(mode).unwrap_or_default());
+    render_scrollable_text(f, app, mode, text, &links, title, area);
 }
 
 /// Renders the skills section
-fn render_skills(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
-    let (mut text, _links) = parse_markdown(&app.skills_content);
-    
-    for line in &mut text.lines {
-        let line_content = line.spans.iter()
-            .map(|span| span.content.to_string())
-            .collect::<String>();
-        
-        if line_content.starts_with("->") && line_content.ends_with("<-") {
-            *line = line.clone().alignment(ratatui::layout::Alignment::Center);
-            
-            if !line.spans.is_empty() {
-                if let Some(first_span) = line.spans.first_mut() {
-                    if first_span.content.starts_with("->") {
-                        let new_content = first_span.content.to_string();
-                        first_span.content = new_content[2..].to_string().into();
-                    }
-                }
-                
-                if let Some(last_span) = line.spans.last_mut() {
-                    if last_span.content.ends_with("<-") {
-                        let new_content = last_span.content.to_string();
-                        last_span.content = new_content[..new_content.len()-2].to_string().into();
-                    }
-                }
-            }
-        }
-    }
-    
-    let paragraph = Paragraph::new(text)
-        .block(Block::default().title("Skills (→ for bar graphs)").borders(Borders::ALL).border_style(Style::default().fg(Color::Blue)))
-        .wrap(Wrap { trim: true });
-    
-    f.render_widget(paragraph, area);
+pub(crate) fn render_skills(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
+    let (text, links) = parse_markdown(&app.skills_content);
+    let title = match app.keymap.key_name(Scope::Content, Action::Right) {
+        Some(key) => format!("Skills ({} for bar graphs)", key),
+        None => String::from("Skills"),
+    };
+    render_scrollable_text(f, app, DisplayMode::Skills, text, &links, &title, area);
 }
 
 /// Renders the projects section
-fn render_projects(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
-    let (mut text, _links) = parse_markdown(&app.projects_content);
-    
-    for line in &mut text.lines {
-        let line_content = line.spans.iter()
-            .map(|span| span.content.to_string())
-            .collect::<String>();
-        
-        if line_content.starts_with("->") && line_content.ends_with("<-") {
-            *line = line.clone().alignment(ratatui::layout::Alignment::Center);
-            
-            if !line.spans.is_empty() {
-                if let Some(first_span) = line.spans.first_mut() {
-                    if first_span.content.starts_with("->") {
-                        let new_content = first_span.content.to_string();
-                        first_span.content = new_content[2..].to_string().into();
-                    }
-                }
-                
-                if let Some(last_span) = line.spans.last_mut() {
-                    if last_span.content.ends_with("<-") {
-                        let new_content = last_span.content.to_string();
-                        last_span.content = new_content[..new_content.len()-2].to_string().into();
-                    }
-                }
-            }
-        }
-    }
-    
-    let title = "Projects (→ for links)";
-    
-    let paragraph = Paragraph::new(text)
-        .block(Block::default().title(title).borders(Borders::ALL).border_style(Style::default().fg(Color::Blue)))
-        .wrap(Wrap { trim: true });
-    
-    f.render_widget(paragraph, area);
+pub(crate) fn render_projects(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
+    let (text, links) = parse_markdown(&app.projects_content);
+    let title = match app.keymap.key_name(Scope::Content, Action::Right) {
+        Some(key) => format!("Projects ({} for links)", key),
+        None => String::from("Projects"),
+    };
+    render_scrollable_text(f, app, DisplayMode::Projects, text, &links, &title, area);
 }
 
 /// Renders the project links for navigation
-fn render_project_links(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
+pub(crate) fn render_project_links(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
+    let theme = app.display_theme();
     if app.project_links.is_empty() {
         let message = Paragraph::new("No project links found.")
             .alignment(Alignment::Center)
             .block(Block::default()
                 .title("Project Links")
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Blue)));
+                .border_style(Style::default().fg(theme.border)));
         
         f.render_widget(message, area);
         return;
     }
     
+    let open = match app.keymap.key_name(Scope::ProjectLinks, Action::Select) {
+        Some(key) => format!("{} or click to open", key),
+        None => String::from("Click to open"),
+    };
+    let back = app.keymap.key_name(Scope::ProjectLinks, Action::Left).or_else(|| app.keymap.key_name(Scope::ProjectLinks, Action::Back));
+    let title = match back {
+        Some(key) => format!("Project Links ({}, {} to go back)", open, key),
+        None => format!("Project Links ({})", open),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(area);
+    push_list_click_targets(app, inner, app.project_links.len(), ClickTarget::ProjectLink);
+    if app.hyperlinks {
+        for (link, y) in app.project_links.iter().zip(inner.top()..inner.bottom()) {
+            let width = (link.text.width() as u16).min(inner.width.saturating_sub(2));
+            app.hyperlink_targets.push((Rect::new(inner.x + 2, y, width, 1), link.url.clone()));
+        }
+    }
+
     let items: Vec<ListItem> = app.project_links
         .iter()
         .enumerate()
         .map(|(i, link)| {
             let style = if i == app.link_index {
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                theme.selected()
             } else {
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::UNDERLINED)
+                theme.link_style()
             };
             
             ListItem::new(Line::from(vec![
                 Span::raw("  "),
                 Span::styled(&link.text, style),
                 Span::raw(" - "),
-                Span::styled(&link.url, Style::default().fg(Color::DarkGray)),
+                Span::styled(&link.url, Style::default().fg(theme.muted)),
             ]))
         })
         .collect();
     
     let links_list = List::new(items)
-        .block(Block::default()
-            .title("Project Links (Enter to open, ← to go back)")
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Blue)))
+        .block(block)
         .highlight_style(Style::default().add_modifier(Modifier::BOLD));
     
     f.render_widget(links_list, area);
 }
 
 /// Renders the skills visualization with bar graphs
-fn render_skills_visual(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
+pub(crate) fn render_skills_visual(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
+    let theme = app.display_theme();
     if app.skills_data.categories.is_empty() {
         return;
     }
@@ -278,7 +449,7 @@ fn render_skills_visual(f: &mut Frame, app: &mut App, area: ratatui::layout::Rec
     let block = Block::default()
         .title(format!("Skills: {}", category.name))
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Blue));
+        .border_style(Style::default().fg(theme.border));
     
     f.render_widget(block.clone(), area);
     
@@ -294,15 +465,16 @@ fn render_skills_visual(f: &mut Frame, app: &mut App, area: ratatui::layout::Rec
     
     let mut header_text = vec![Span::styled(
         "Skills are shown in their original order.", 
-        Style::default().fg(Color::Gray)
+        Style::default().fg(theme.text)
     )];
     
     let skills_area_height = chunks[1].height.saturating_sub(2);
     let skills_per_page = (skills_area_height / 3) as usize;
     
     let skills_per_page = skills_per_page.max(1);
+    app.skills_per_page = skills_per_page;
     
-    let total_pages = (category.skills.len() + skills_per_page - 1) / skills_per_page;
+    let total_pages = category.skills.len().div_ceil(skills_per_page);
     
     if app.skills_page >= total_pages && total_pages > 0 {
         app.skills_page = total_pages - 1;
@@ -311,8 +483,8 @@ fn render_skills_visual(f: &mut Frame, app: &mut App, area: ratatui::layout::Rec
     if total_pages > 1 {
         header_text.push(Span::raw(" "));
         header_text.push(Span::styled(
-            format!("Page {}/{}. Use ←/→ to navigate pages.", app.skills_page + 1, total_pages),
-            Style::default().fg(Color::Gray)
+            page_label(app, total_pages),
+            Style::default().fg(theme.text)
         ));
     }
     
@@ -347,7 +519,7 @@ fn render_skills_visual(f: &mut Frame, app: &mut App, area: ratatui::layout::Rec
         let chunk_base_idx = display_idx * 3;
         
         let name_paragraph = Paragraph::new(skill.name.as_str())
-            .style(Style::default().fg(Color::Gray));
+            .style(Style::default().fg(theme.text));
         
         if chunk_base_idx < skill_chunks.len() {
             f.render_widget(name_paragraph, skill_chunks[chunk_base_idx]);
@@ -355,7 +527,7 @@ fn render_skills_visual(f: &mut Frame, app: &mut App, area: ratatui::layout::Rec
         
         if chunk_base_idx + 1 < skill_chunks.len() {
             let gauge = Gauge::default()
-                .gauge_style(Style::default().fg(Color::Green))
+                .gauge_style(Style::default().fg(theme.gauge))
                 .ratio(skill.level as f64 / 100.0)
                 .label(format!("{}%", skill.level));
             
@@ -367,9 +539,151 @@ fn render_skills_visual(f: &mut Frame, app: &mut App, area: ratatui::layout::Rec
 
 
 /// Renders the Contact Information section
-fn render_contact(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
-    let (mut text, _links) = parse_markdown(&app.contact_content);
-    
+pub(crate) fn render_contact(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
+    let (text, links) = parse_markdown(&app.contact_content);
+    render_scrollable_text(f, app, DisplayMode::Contact, text, &links, "Contact Information", area);
+}
+
+/// Renders text in a pane that scrolls by wrapped lines, with a scrollbar and indicators for hidden lines
+fn render_scrollable_text(f: &mut Frame, app: &mut App, mode: DisplayMode, mut text: Text<'static>, links: &[Link], title: &str, area: Rect) {
+    let theme = app.display_theme();
+    center_marked_lines(&mut text);
+    for link in links {
+        if let Some(line) = text.lines.get_mut(link.line) {
+            style_columns(line, link.start_column, link.end_column, theme.link_style());
+        }
+    }
+    highlight_search_matches(app, &theme, mode, &mut text);
+
+    let focused_link = app.link_focus.filter(|focus| focus.mode == mode && focus.index < links.len());
+    if let Some(focus) = focused_link {
+        let link = &links[focus.index];
+        if let Some(line) = text.lines.get_mut(link.line) {
+            style_columns(line, link.start_column, link.end_column, theme.focused());
+        }
+    }
+
+    let block = Block::default().title(title.to_string()).borders(Borders::ALL).border_style(Style::default().fg(theme.border));
+    let inner_area = block.inner(area);
+    let line_rows = wrapped_line_rows(&text, inner_area.width);
+    let link_row = |link: &Link, column: usize| {
+        let line = text.lines.get(link.line).map_or((0, 0), |line| wrapped_position(line, column, inner_area.width));
+        (line_rows.get(link.line).copied().unwrap_or(0) + line.0, line.1)
+    };
+
+    let jump_row = match app.search.pending_scroll {
+        Some((pending_mode, line)) if pending_mode == mode => {
+            app.search.pending_scroll = None;
+            line_rows.get(line).copied()
+        }
+        _ => None,
+    };
+    let reveal_row = focused_link
+        .filter(|focus| focus.reveal)
+        .map(|focus| link_row(&links[focus.index], links[focus.index].start_column).0);
+
+    let paragraph = Paragraph::new(text.clone()).wrap(Wrap { trim: true });
+    let content_height = u16::try_from(paragraph.line_count(inner_area.width)).unwrap_or(u16::MAX);
+
+    let scroll = app.scroll_state_mut(mode);
+    if let Some(row) = jump_row {
+        scroll.offset = u16::try_from(row).unwrap_or(u16::MAX);
+    }
+    if let Some(row) = reveal_row.and_then(|row| u16::try_from(row).ok()) {
+        if row < scroll.offset {
+            scroll.offset = row;
+        } else if row >= scroll.offset + scroll.viewport_height.max(1) {
+            scroll.offset = row + 1 - scroll.viewport_height.max(1);
+        }
+    }
+    scroll.set_dimensions(content_height, inner_area.height);
+    let scroll = *scroll;
+    if let Some(focus) = &mut app.link_focus {
+        focus.reveal = false;
+    }
+
+    let indicator_style = Style::default().fg(theme.muted);
+    let mut block = block;
+    if scroll.has_more_above() {
+        block = block.title(Line::styled("▲ more above ", indicator_style).right_aligned());
+    }
+    if scroll.has_more_below() {
+        let label = match app.keymap.key_name(app.key_scope(), Action::PageDown) {
+            Some(key) => format!("▼ more below ({}) ", key),
+            None => String::from("▼ more below "),
+        };
+        block = block.title_bottom(Line::styled(label, indicator_style).right_aligned());
+    }
+
+    f.render_widget(paragraph.block(block).scroll((scroll.offset, 0)), area);
+
+    for (i, link) in links.iter().enumerate() {
+        let start = link_row(link, link.start_column);
+        let end = link_row(link, link.end_column.saturating_sub(1).max(link.start_column));
+        for row in start.0..=end.0 {
+            let Some(y) = row.checked_sub(usize::from(scroll.offset)).filter(|y| *y < usize::from(inner_area.height)) else {
+                continue;
+            };
+            let first = if row == start.0 { start.1 } else { 0 };
+            let last = if row == end.0 { end.1 + 1 } else { usize::from(inner_area.width) };
+            let width = last.min(usize::from(inner_area.width)).saturating_sub(first);
+            if width > 0 {
+                let rect = Rect::new(inner_area.x + first as u16, inner_area.y + y as u16, width as u16, 1);
+                app.click_targets.push((rect, ClickTarget::InlineLink(mode, i)));
+                if app.hyperlinks {
+                    app.hyperlink_targets.push((rect, link.url.clone()));
+                }
+            }
+        }
+    }
+
+    if scroll.max_offset() > 0 {
+        let mut scrollbar_state = ScrollbarState::new(usize::from(scroll.max_offset()))
+            .position(usize::from(scroll.offset))
+            .viewport_content_length(usize::from(inner_area.height));
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None);
+        f.render_stateful_widget(scrollbar, area.inner(Margin { vertical: 1, horizontal: 0 }), &mut scrollbar_state);
+    }
+}
+
+/// Returns the wrapped row each line of `text` starts on
+fn wrapped_line_rows(text: &Text<'static>, width: u16) -> Vec<usize> {
+    let mut row = 0;
+    text.lines
+        .iter()
+        .map(|line| {
+            let start = row;
+            row += Paragraph::new(line.clone()).wrap(Wrap { trim: true }).line_count(width);
+            start
+        })
+        .collect()
+}
+
+/// Highlights search matches in a pane, marking the selected match more strongly
+fn highlight_search_matches(app: &App, theme: &Theme, mode: DisplayMode, text: &mut Text<'static>) {
+    if app.search.matches.is_empty() {
+        return;
+    }
+
+    let selected_line = match app.selected_search_location() {
+        Some(SearchLocation::Line(match_mode, line)) if match_mode == mode => Some(line),
+        _ => None,
+    };
+
+    for (i, line) in text.lines.iter_mut().enumerate() {
+        let style = if Some(i) == selected_line {
+            theme.focused()
+        } else {
+            theme.matched()
+        };
+        highlight_matches(line, &app.search.query, style);
+    }
+}
+
+/// Centers lines wrapped in `->` and `<-` markers, removing the markers
+fn center_marked_lines(text: &mut Text<'static>) {
     for line in &mut text.lines {
         let line_content = line.spans.iter()
             .map(|span| span.content.to_string())
@@ -395,22 +709,19 @@ fn render_contact(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
             }
         }
     }
-    
-    let paragraph = Paragraph::new(text)
-        .block(Block::default().title("Contact Information").borders(Borders::ALL).border_style(Style::default().fg(Color::Blue)))
-        .wrap(Wrap { trim: true });
-    
-    f.render_widget(paragraph, area);
 }
 
 /// Renders the Timeline section with a horizontal timeline visualization
-fn render_timeline(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+pub(crate) fn render_timeline(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
+    let (_, lane_count) = timeline_span_lanes(&app.get_filtered_events(), YearMonth::today());
+    let timeline_height = 4 + lane_count.max(1) as u16;
+    
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints(
             [
                 Constraint::Length(2),
-                Constraint::Length(5),
+                Constraint::Length(timeline_height),
                 Constraint::Min(0),
             ]
             .as_ref(),
@@ -418,45 +729,23 @@ fn render_timeline(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
         .split(area);
     
     let (mut text, _) = parse_markdown(&app.timeline_content);
-    
-    for line in &mut text.lines {
-        let line_content = line.spans.iter()
-            .map(|span| span.content.to_string())
-            .collect::<String>();
-        
-        if line_content.starts_with("->") && line_content.ends_with("<-") {
-            *line = line.clone().alignment(ratatui::layout::Alignment::Center);
-            
-            if !line.spans.is_empty() {
-                if let Some(first_span) = line.spans.first_mut() {
-                    if first_span.content.starts_with("->") {
-                        let new_content = first_span.content.to_string();
-                        first_span.content = new_content[2..].to_string().into();
-                    }
-                }
-                
-                if let Some(last_span) = line.spans.last_mut() {
-                    if last_span.content.ends_with("<-") {
-                        let new_content = last_span.content.to_string();
-                        last_span.content = new_content[..new_content.len()-2].to_string().into();
-                    }
-                }
-            }
-        }
-    }
+    center_marked_lines(&mut text);
     
     let instructions = Paragraph::new(text)
-        .block(Block::default().title("Career Timeline").borders(Borders::ALL).border_style(Style::default().fg(Color::Blue)))
+        .block(Block::default().title("Career Timeline").borders(Borders::ALL).border_style(Style::default().fg(app.display_theme().border)))
         .wrap(Wrap { trim: true });
     f.render_widget(instructions, chunks[0]);
     
     let timeline_area = chunks[1];
     
-    if !app.timeline_events.is_empty() {
-        let _ = app.timeline_index.min(app.timeline_events.len() - 1);
-        
+    if !app.get_filtered_events().is_empty() {
         render_horizontal_timeline(f, app, timeline_area);
         render_timeline_details(f, app, chunks[2]);
+    } else if !app.timeline_events.is_empty() {
+        let empty_msg = Paragraph::new(format!("No timeline events match the {} filter.", app.timeline_filter.label()))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL));
+        f.render_widget(empty_msg, chunks[1]);
     } else {
         let empty_msg = Paragraph::new("No timeline events found.")
             .alignment(Alignment::Center)
@@ -465,36 +754,40 @@ fn render_timeline(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     }
 }
 
-/// Renders the horizontal timeline with year markers and points
-fn render_horizontal_timeline(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+/// Renders the horizontal timeline with year markers, points, and bars for date spans
+fn render_horizontal_timeline(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
+    let theme = app.display_theme();
+    let events = app.get_filtered_events();
+    let today = YearMonth::today();
+    let (lanes, lane_count) = timeline_span_lanes(&events, today);
+    
     let block = Block::default()
-        .title("Navigate with ← →")
+        .title(timeline_title(app))
+        .title_bottom(timeline_legend(app, &theme).right_aligned())
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Blue));
+        .border_style(Style::default().fg(theme.border));
     f.render_widget(block.clone(), area);
     
     let inner_area = block.inner(area);
     
-    let min_year = app.timeline_events.iter().map(|e| e.year).min().unwrap_or(2000);
-    let max_year = app.timeline_events.iter().map(|e| e.year).max().unwrap_or(2024);
-    
-    let timeline_width = inner_area.width as usize;
-    let year_span = (max_year - min_year) as usize;
-    let pixels_per_year = if year_span > 0 {
-        timeline_width / year_span
-    } else {
-        timeline_width
-    };
+    let first_month = events.iter().map(|e| e.month_range(today).0).min().unwrap_or(0);
+    let last_month = events.iter().map(|e| e.month_range(today).1).max().unwrap_or(0);
+    let month_span = u64::from((last_month - first_month).max(1));
     
     let horizontal_padding = 4;
     let usable_width = inner_area.width.saturating_sub(horizontal_padding * 2);
+    let x_for = |month: u32| {
+        let offset = u64::from(month - first_month) * u64::from(usable_width.saturating_sub(1)) / month_span;
+        inner_area.x + horizontal_padding + offset as u16
+    };
     
-    let line_y = inner_area.y + inner_area.height / 2;
+    let line_y = inner_area.y + lane_count.max(1) as u16;
+    let bottom = inner_area.y + inner_area.height;
     
     let timeline_text = "─".repeat(usable_width as usize);
     let timeline_line = Line::from(Span::styled(
         timeline_text,
-        Style::default().fg(Color::Gray)
+        Style::default().fg(theme.text)
     ));
     let timeline_paragraph = Paragraph::new(timeline_line);
     let timeline_area = Rect {
@@ -503,25 +796,35 @@ fn render_horizontal_timeline(f: &mut Frame, app: &App, area: ratatui::layout::R
         width: usable_width,
         height: 1,
     };
-    f.render_widget(timeline_paragraph, timeline_area);
-    
-    let mut event_positions = Vec::new();
+    if line_y < bottom {
+        f.render_widget(timeline_paragraph, timeline_area);
+    }
     
-    for (i, event) in app.timeline_events.iter().enumerate() {
-        let year_offset = (event.year - min_year) as usize;
-        let width_ratio = usable_width as f32 / timeline_width as f32;
-        let adjusted_offset = (year_offset as f32 * pixels_per_year as f32 * width_ratio) as u16;
-        let x_pos = inner_area.x + horizontal_padding + adjusted_offset;
-        
-        event_positions.push(x_pos);
+    let mut click_targets = Vec::new();
+    for (i, event) in events.iter().enumerate() {
+        let (start_month, end_month) = event.month_range(today);
+        let x_pos = x_for(start_month);
+        let style = if i == app.timeline_index { theme.selected() } else { Style::default().fg(theme.timeline_color(event.event_type)) };
         
-        if x_pos < inner_area.x + inner_area.width {
-            let symbol = if i == app.timeline_index { "●" } else { "○" };
-            let color = if i == app.timeline_index { Color::Yellow } else { Color::White };
+        if let Some(lane) = lanes[i] {
+            let bar_y = inner_area.y + lane as u16;
+            let bar_width = x_for(end_month) - x_pos + 1;
             
+            if bar_y < line_y {
+                let bar = Paragraph::new(Line::from(Span::styled(
+                    "━".repeat(bar_width as usize),
+                    style
+                )));
+                let bar_area = Rect { x: x_pos, y: bar_y, width: bar_width, height: 1 };
+                f.render_widget(bar, bar_area);
+                click_targets.push((bar_area, ClickTarget::TimelineEvent(i)));
+            }
+        }
+        
+        if x_pos < inner_area.x + inner_area.width && line_y < bottom {
             let point_paragraph = Paragraph::new(Line::from(Span::styled(
-                symbol,
-                Style::default().fg(color)
+                event.event_type.symbol(),
+                style
             )));
             
             let point_area = Rect {
@@ -531,15 +834,16 @@ fn render_horizontal_timeline(f: &mut Frame, app: &App, area: ratatui::layout::R
                 height: 1,
             };
             f.render_widget(point_paragraph, point_area);
+            click_targets.push((point_area, ClickTarget::TimelineEvent(i)));
             
             let year_text = event.year.to_string();
             let year_x = x_pos.saturating_sub((year_text.len() / 2) as u16);
             let year_len = year_text.len() as u16;
             
-            if year_x + year_len < inner_area.x + inner_area.width {
+            if year_x + year_len < inner_area.x + inner_area.width && line_y + 1 < bottom {
                 let year_paragraph = Paragraph::new(Line::from(Span::styled(
                     year_text,
-                    Style::default().fg(color)
+                    style
                 )));
                 
                 let year_area = Rect {
@@ -549,18 +853,63 @@ fn render_horizontal_timeline(f: &mut Frame, app: &App, area: ratatui::layout::R
                     height: 1,
                 };
                 f.render_widget(year_paragraph, year_area);
+                click_targets.push((year_area, ClickTarget::TimelineEvent(i)));
             }
         }
     }
+
+    app.click_targets.extend(click_targets);
+}
+
+/// Titles the timeline with its navigation keys and the active filter
+fn timeline_title(app: &App) -> String {
+    let mut parts = Vec::new();
+    if let (Some(left), Some(right)) = (app.keymap.key_name(Scope::Timeline, Action::Left), app.keymap.key_name(Scope::Timeline, Action::Right)) {
+        parts.push(format!("Navigate with {} {}", left, right));
+    }
+    match app.keymap.key_name(Scope::Timeline, Action::NextFilter) {
+        Some(key) => parts.push(format!("Filter: {} ({})", app.timeline_filter.label(), key)),
+        None => parts.push(format!("Filter: {}", app.timeline_filter.label())),
+    }
+    parts.join(" | ")
+}
+
+/// Labels the skills page, naming the keys that turn it
+fn page_label(app: &App, total_pages: usize) -> String {
+    let page = format!("Page {}/{}.", app.skills_page + 1, total_pages);
+    match (app.keymap.key_name(Scope::SkillsVisual, Action::Left), app.keymap.key_name(Scope::SkillsVisual, Action::Right)) {
+        (Some(left), Some(right)) => format!("{} Use {}/{} to navigate pages.", page, left, right),
+        _ => page,
+    }
+}
+
+/// Builds a legend of the timeline types present in the timeline
+fn timeline_legend(app: &App, theme: &Theme) -> Line<'static> {
+    let mut spans = Vec::new();
+
+    for event_type in TimelineType::ALL {
+        if !app.timeline_events.iter().any(|event| event.event_type == event_type) {
+            continue;
+        }
+
+        spans.push(Span::styled(
+            format!(" {} {} ", event_type.symbol(), event_type.label()),
+            Style::default().fg(theme.timeline_color(event_type)),
+        ));
+    }
+
+    Line::from(spans)
 }
 
 /// Renders the details for the selected timeline event
 fn render_timeline_details(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
-    if app.timeline_events.is_empty() {
+    let theme = app.display_theme();
+    let events = app.get_filtered_events();
+    if events.is_empty() {
         return;
     }
     
-    let event = &app.timeline_events[app.timeline_index];
+    let event = events[app.timeline_index.min(events.len() - 1)];
     
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -575,18 +924,27 @@ fn render_timeline_details(f: &mut Frame, app: &App, area: ratatui::layout::Rect
         )
         .split(area);
     
+    let mut title_block = Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.border));
+    if let (Some(range), Some(months)) = (event.date_range_label(), event.duration_months(YearMonth::today())) {
+        title_block = title_block.title(format!("{} ({})", range, format_duration(months)));
+    }
+    
     let title = format!("{} | {}", event.title, event.organization);
     let title_paragraph = Paragraph::new(Line::from(vec![
-        Span::styled(title, Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+        Span::styled(
+            format!("{} {} ", event.event_type.symbol(), event.event_type.label()),
+            Style::default().fg(theme.timeline_color(event.event_type)),
+        ),
+        Span::styled(title, Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD))
     ]))
-    .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Blue)))
+    .block(title_block)
     .alignment(Alignment::Center);
     f.render_widget(title_paragraph, chunks[0]);
     
     let desc_paragraph = Paragraph::new(Line::from(vec![
         Span::raw(event.description.clone())
     ]))
-    .block(Block::default().title("Description").borders(Borders::ALL).border_style(Style::default().fg(Color::Blue)))
+    .block(Block::default().title("Description").borders(Borders::ALL).border_style(Style::default().fg(theme.border)))
     .wrap(Wrap { trim: true });
     f.render_widget(desc_paragraph, chunks[1]);
     
@@ -597,15 +955,15 @@ fn render_timeline_details(f: &mut Frame, app: &App, area: ratatui::layout::Rect
         .unwrap_or_default();
     
     let highlights_list = List::new(highlights)
-        .block(Block::default().title("Highlights").borders(Borders::ALL).border_style(Style::default().fg(Color::Blue)))
+        .block(Block::default().title("Highlights").borders(Borders::ALL).border_style(Style::default().fg(theme.border)))
         .style(Style::default());
     f.render_widget(highlights_list, chunks[2]);
     
     let tech_text = event.technologies.as_ref().map_or(String::new(), |techs| techs.join(" | "));
     let tech_paragraph = Paragraph::new(Line::from(vec![
-        Span::styled(tech_text, Style::default().fg(Color::Green))
+        Span::styled(tech_text, Style::default().fg(theme.gauge))
     ]))
-    .block(Block::default().title("Technologies").borders(Borders::ALL).border_style(Style::default().fg(Color::Blue)))
+    .block(Block::default().title("Technologies").borders(Borders::ALL).border_style(Style::default().fg(theme.border)))
     .alignment(Alignment::Center);
     f.render_widget(tech_paragraph, chunks[3]);
 }
@@ -3,9 +3,19 @@ mod common;
 #[test]
 fn test_cli_about_command() {
     let stdout = common::run_cli_with_args(&["about"]);
-    assert!(stdout.contains("About David Parker"));
+    assert!(stdout.contains("David Parker"));
     assert!(stdout.contains("Warp team"));
 }
 
 // Note: We can't easily test the TUI in an automated way since it requires interaction,
 // so we've removed the tests for the "run" command that now launches the TUI
+
+#[test]
+fn test_cli_section_commands_are_plain_when_piped() {
+    let stdout = common::run_cli_with_args(&["timeline", "--type", "education"]);
+    assert!(stdout.contains("[Education]"));
+    assert!(!stdout.contains('\x1b'));
+
+    let stdout = common::run_cli_with_args(&["contact", "--field", "github"]);
+    assert_eq!(stdout.trim(), "github.com/davidparkercodes");
+}
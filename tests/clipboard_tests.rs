@@ -0,0 +1,110 @@
+mod common;
+
+use std::collections::HashMap;
+
+use hire_david_parker::tui::clipboard::{copy, copy_text, copy_with_commands, local_commands_from, osc52_sequence, osc52_supported_from, CLIPBOARD_ENV};
+use hire_david_parker::tui::state::App;
+
+fn local_commands(vars: &[(&str, &str)]) -> Vec<String> {
+    let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    local_commands_from(|name| vars.get(name).cloned())
+}
+
+fn osc52_supported(vars: &[(&str, &str)]) -> bool {
+    let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    osc52_supported_from(|name| vars.get(name).cloned())
+}
+
+#[test]
+fn test_environment_command_overrides_detection() {
+    assert_eq!(local_commands(&[(CLIPBOARD_ENV, "tmux load-buffer -"), ("SSH_TTY", "/dev/pts/0")]), ["tmux load-buffer -"]);
+}
+
+#[test]
+fn test_no_local_commands_over_ssh() {
+    assert!(local_commands(&[("SSH_CONNECTION", "10.0.0.1 22 10.0.0.2 22"), ("DISPLAY", ":0")]).is_empty());
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+#[test]
+fn test_display_server_picks_commands() {
+    assert_eq!(local_commands(&[("WAYLAND_DISPLAY", "wayland-0")])[0], "wl-copy");
+    assert_eq!(local_commands(&[("DISPLAY", ":0")])[0], "xclip -selection clipboard");
+    assert!(local_commands(&[]).is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_copy_tries_commands_in_order() {
+    let commands = ["definitely-not-a-clipboard".to_string(), "false".to_string(), "cat".to_string()];
+    assert_eq!(copy_with_commands(&commands, "david@redkey.io"), Ok("cat".to_string()));
+}
+
+#[test]
+fn test_copy_reports_failures() {
+    let error = copy_with_commands(&["definitely-not-a-clipboard".to_string()], "text").unwrap_err();
+    assert!(error.contains("definitely-not-a-clipboard"));
+
+    let error = copy_with_commands(&[], "text").unwrap_err();
+    assert!(error.contains("no clipboard command"));
+}
+
+#[test]
+fn test_osc52_copy() {
+    assert_eq!(osc52_sequence("hi"), "\x1b]52;c;aGk=\x07");
+
+    let mut output = Vec::new();
+    copy(&mut output, "david@redkey.io").unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), osc52_sequence("david@redkey.io"));
+}
+
+#[test]
+fn test_osc52_support_detection() {
+    assert!(osc52_supported(&[("TERM", "xterm-kitty")]));
+    assert!(osc52_supported(&[("TERM", "xterm-256color"), ("TERM_PROGRAM", "WezTerm")]));
+    assert!(osc52_supported(&[("TERM", "xterm-256color"), ("SSH_TTY", "/dev/pts/0")]));
+    assert!(!osc52_supported(&[("TERM", "xterm-256color"), ("VTE_VERSION", "7600")]));
+    assert!(!osc52_supported(&[("TERM", "dumb"), ("SSH_TTY", "/dev/pts/0")]));
+    assert!(!osc52_supported(&[("TERM", "xterm-kitty"), (CLIPBOARD_ENV, "tmux load-buffer -")]));
+}
+
+#[test]
+fn test_copy_text_prefers_osc52() {
+    let mut output = Vec::new();
+    let receiver = copy_text(&mut output, "david@redkey.io", true, vec!["definitely-not-a-clipboard".to_string()]);
+
+    assert_eq!(receiver.recv().unwrap(), Ok("the terminal".to_string()));
+    assert_eq!(String::from_utf8(output).unwrap(), osc52_sequence("david@redkey.io"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_copy_text_falls_back_to_commands() {
+    let mut output = Vec::new();
+    let receiver = copy_text(&mut output, "david@redkey.io", false, vec!["cat".to_string()]);
+
+    assert_eq!(receiver.recv().unwrap(), Ok("cat".to_string()));
+    assert!(output.is_empty());
+
+    let receiver = copy_text(&mut output, "david@redkey.io", false, vec!["false".to_string()]);
+    let error = receiver.recv().unwrap().unwrap_err();
+    assert!(error.contains("OSC 52"));
+    assert!(error.contains("false"));
+}
+
+#[test]
+fn test_copy_status_follows_the_outcome() {
+    let mut app = App::new();
+    app.copy_to_clipboard("david@redkey.io");
+    assert!(!app.status.as_ref().unwrap().text.starts_with("Copied"));
+
+    app.finish_copy("david@redkey.io", Ok("the terminal".to_string()));
+    let status = app.status.clone().unwrap();
+    assert!(!status.is_error);
+    assert_eq!(status.text, "Copied david@redkey.io to the clipboard");
+
+    app.finish_copy("david@redkey.io", Err("no clipboard command is available".to_string()));
+    let status = app.status.clone().unwrap();
+    assert!(status.is_error);
+    assert!(status.text.contains("no clipboard command"));
+}
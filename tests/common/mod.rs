@@ -1,11 +1,20 @@
+#![allow(dead_code)]
+
 // Common test utilities can be added here
 
 /// Run the CLI with the given arguments and return the stdout output
+///
+/// The content directory is an empty one, so the output comes from the embedded content
+/// whatever the user has imported on this machine.
 pub fn run_cli_with_args(args: &[&str]) -> String {
+    let content_dir = std::env::temp_dir().join(format!("hdp-cli-embedded-{}", std::process::id()));
+    std::fs::create_dir_all(&content_dir).expect("Failed to create the content directory");
+
     let output = std::process::Command::new("cargo")
         .arg("run")
         .arg("--")
         .args(args)
+        .env("HIRE_DAVID_PARKER_CONTENT_DIR", &content_dir)
         .output()
         .expect("Failed to execute command");
     
@@ -0,0 +1,135 @@
+mod common;
+
+use hire_david_parker::content::{embedded_file, xdg_content_dir, ContentError, ContentSource, EMBEDDED_CONTENT};
+use hire_david_parker::{load_project_links, load_skills_data, load_timeline_data, set_content_source};
+use std::fs;
+use std::path::PathBuf;
+
+// Helper function to create an empty, uniquely named content directory
+fn create_content_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("hdp-content-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_embedded_source_reads_static_content() {
+    let content = ContentSource::Embedded.read("contact.md").unwrap();
+    assert!(content.contains("Email"));
+}
+
+#[test]
+fn test_every_static_content_file_is_embedded() {
+    let static_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src/static/content");
+
+    for entry in fs::read_dir(static_dir).unwrap() {
+        let path = entry.unwrap().path();
+        let filename = path.file_name().unwrap().to_str().unwrap();
+        let embedded = embedded_file(filename);
+
+        assert!(embedded.is_some(), "{} is not embedded", filename);
+        assert_eq!(embedded.unwrap(), fs::read_to_string(&path).unwrap());
+    }
+
+    assert!(!EMBEDDED_CONTENT.is_empty());
+}
+
+#[test]
+fn test_directory_source_prefers_external_files() {
+    let dir = create_content_dir("external");
+    fs::write(dir.join("about.md"), "## Custom About").unwrap();
+
+    let source = ContentSource::Directory(dir.clone());
+    assert_eq!(source.read("about.md").unwrap(), "## Custom About");
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_directory_source_falls_back_to_embedded() {
+    let dir = create_content_dir("fallback");
+
+    let source = ContentSource::Directory(dir.clone());
+    let embedded = ContentSource::Embedded.read("timeline.json").unwrap();
+    assert_eq!(source.read("timeline.json").unwrap(), embedded);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_missing_file_is_an_error() {
+    let result = ContentSource::Embedded.read("nonexistent_file.md");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_resolve_prefers_explicit_directory() {
+    let source = ContentSource::resolve(Some(PathBuf::from("/tmp/explicit")));
+    assert_eq!(source, ContentSource::Directory(PathBuf::from("/tmp/explicit")));
+}
+
+#[test]
+fn test_xdg_content_dir_location() {
+    if let Some(dir) = xdg_content_dir() {
+        assert!(dir.ends_with("hire-david-parker/content"));
+    }
+}
+
+#[test]
+fn test_json_syntax_error_reports_position() {
+    let error = serde_json::from_str::<serde_json::Value>("{\n  \"links\": [,]\n}").unwrap_err();
+
+    match ContentError::from_json("projects.json", error) {
+        ContentError::Parse { file, line, column, .. } => {
+            assert_eq!(file, "projects.json");
+            assert_eq!(line, 2);
+            assert!(column > 0);
+        }
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_content_error_display_names_file() {
+    let error = ContentError::schema("skills.json", "skill 'Rust' has level 120, expected 0-100");
+    assert_eq!(error.file(), "skills.json");
+    assert!(error.to_string().contains("skills.json"));
+    assert!(error.to_string().contains("level 120"));
+}
+
+#[test]
+fn test_loaders_report_typed_errors() {
+    let dir = create_content_dir("broken");
+    fs::write(dir.join("timeline.json"), "[\n  { \"year\": 2020,\n").unwrap();
+    fs::write(dir.join("projects.json"), "{ \"links\": [{ \"text\": \"Site\" }] }").unwrap();
+    fs::write(dir.join("skills.json"), "{ \"categories\": [{ \"name\": \"Languages\", \"skills\": [{ \"name\": \"Rust\", \"level\": 120 }] }] }").unwrap();
+
+    set_content_source(ContentSource::Directory(dir.clone()));
+    let timeline = load_timeline_data();
+    let projects = load_project_links();
+    let skills = load_skills_data();
+    set_content_source(ContentSource::Embedded);
+
+    assert!(matches!(timeline, Err(ContentError::Parse { line: 3, .. })));
+    assert!(matches!(projects, Err(ContentError::Schema { .. })));
+    assert!(matches!(skills, Err(ContentError::Schema { ref message, .. }) if message.contains("Rust")));
+
+    fs::write(dir.join("timeline.json"), r#"[{ "year": 2020, "title": "Role", "organization": "Org", "description": "D",
+        "highlights": null, "technologies": null, "start": "2021-05", "end": "2020-01" }]"#).unwrap();
+    set_content_source(ContentSource::Directory(dir.clone()));
+    let reversed_dates = load_timeline_data();
+    set_content_source(ContentSource::Embedded);
+
+    assert!(matches!(reversed_dates, Err(ContentError::Schema { ref message, .. }) if message.contains("ends before it starts")));
+
+    fs::write(dir.join("timeline.json"), r#"[{ "year": 2020, "title": "Role", "organization": "Org", "description": "D",
+        "highlights": null, "technologies": null, "type": "carrer" }]"#).unwrap();
+    set_content_source(ContentSource::Directory(dir.clone()));
+    let misspelled_type = load_timeline_data();
+    set_content_source(ContentSource::Embedded);
+
+    assert!(matches!(misspelled_type, Err(ContentError::Schema { ref message, .. }) if message.contains("carrer")));
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -2,8 +2,7 @@ mod common;
 
 use std::time::Duration;
 use std::thread;
-use std::sync::mpsc;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
 use hire_david_parker::tui::event::{Event, EventHandler};
 
 #[test]
@@ -18,8 +17,15 @@ fn test_event_enum_debug() {
     let tick_event = Event::Tick;
     let key_event = Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
     let resize_event = Event::Resize(80, 24);
+    let mouse_event = Event::Mouse(MouseEvent {
+        kind: MouseEventKind::ScrollDown,
+        column: 0,
+        row: 0,
+        modifiers: KeyModifiers::NONE,
+    });
     
     assert_eq!(format!("{:?}", tick_event), "Tick");
+    assert!(format!("{:?}", mouse_event).contains("ScrollDown"));
     assert!(format!("{:?}", key_event).contains("Key"));
     assert_eq!(format!("{:?}", resize_event), "Resize(80, 24)");
 }
@@ -34,7 +40,7 @@ fn test_event_handler_debug() {
 #[test]
 fn test_event_clone() {
     let resize_event = Event::Resize(80, 24);
-    let cloned_event = resize_event.clone();
+    let cloned_event = resize_event;
     assert_eq!(format!("{:?}", resize_event), format!("{:?}", cloned_event));
 }
 
@@ -0,0 +1,319 @@
+mod common;
+
+use hire_david_parker::contact::parse_signature;
+use hire_david_parker::export::html::{markdown_to_html, to_html};
+use hire_david_parker::export::latex::{self, markdown_to_latex, to_latex};
+use hire_david_parker::export::template::{load_template, render_template};
+use hire_david_parker::export::typst::{self, markdown_to_typst, to_typst};
+use hire_david_parker::export::json_resume::{import, level_label, parse_level, JsonResume, SCHEMA_URL};
+use hire_david_parker::export::document::{to_markdown, to_text};
+use hire_david_parker::export::{export, ExportFormat, ExportOptions, Resume};
+use hire_david_parker::tui::models::SkillsData;
+use hire_david_parker::{ContentError, ProjectLinks, TimelineDate, TimelineEvent, TimelineType, YearMonth};
+use std::fs;
+use std::path::PathBuf;
+
+// Helper function to create an empty, uniquely named output directory
+fn create_output_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("hdp-import-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+const SAMPLE_RESUME: &str = r#"{
+  "basics": {
+    "name": "Ada Lovelace",
+    "email": "ada@example.com",
+    "summary": "First programmer.",
+    "profiles": [{ "network": "GitHub", "username": "ada", "url": "https://github.com/ada" }]
+  },
+  "work": [{ "name": "Analytical Engines", "position": "Programmer", "startDate": "2019-03", "summary": "Wrote programs",
+             "highlights": ["Note G"], "keywords": ["Rust"] }],
+  "education": [{ "institution": "Home", "area": "Mathematics", "startDate": "2010", "endDate": "2014" }],
+  "certificates": [{ "name": "Certified Engine Operator", "date": "2020-06-15", "issuer": "Babbage" }],
+  "skills": [
+    { "name": "Rust", "level": "Expert", "keywords": ["Languages"] },
+    { "name": "Go", "level": "40%", "keywords": ["Languages"] },
+    { "name": "Leadership", "level": "Advanced" }
+  ],
+  "projects": [
+    { "name": "Engine Docs", "url": "https://example.com/docs", "description": "Documentation" },
+    { "name": "Difference Engine", "startDate": "2018-01", "endDate": "2018-06" }
+  ]
+}"#;
+
+#[test]
+fn test_export_format_from_str() {
+    assert_eq!("json-resume".parse::<ExportFormat>(), Ok(ExportFormat::JsonResume));
+    assert_eq!("HTML".parse::<ExportFormat>(), Ok(ExportFormat::Html));
+    assert!("pdf".parse::<ExportFormat>().unwrap_err().contains("json-resume"));
+    assert_eq!("txt".parse::<ExportFormat>(), Ok(ExportFormat::Text));
+    assert_eq!("md".parse::<ExportFormat>(), Ok(ExportFormat::Markdown));
+    assert_eq!(ExportFormat::Html.file_name(), "index.html");
+    assert_eq!(ExportFormat::Markdown.file_name(), "resume.md");
+    assert_eq!("latex".parse::<ExportFormat>(), Ok(ExportFormat::Latex));
+    assert_eq!(ExportFormat::Typst.file_name(), "resume.typ");
+}
+
+#[test]
+fn test_parse_signature() {
+    assert_eq!(parse_signature("Hi!\n\n- **Email:** a@b.c\n\n**Ada Lovelace** 🤘\n"), Some(String::from("Ada Lovelace")));
+    assert_eq!(parse_signature("No sign-off here"), None);
+}
+
+#[test]
+fn test_skill_levels() {
+    assert_eq!(level_label(95), "Master");
+    assert_eq!(level_label(50), "Intermediate");
+    assert_eq!(parse_level("Expert"), 90);
+    assert_eq!(parse_level("85%"), 85);
+    assert_eq!(parse_level("250"), 100);
+    assert_eq!(parse_level("unknown"), 50);
+}
+
+#[test]
+fn test_export_embedded_resume() {
+    let json = export(&Resume::load().unwrap(), ExportFormat::JsonResume, &ExportOptions::default()).unwrap();
+    let document: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(document["$schema"], SCHEMA_URL);
+    assert_eq!(document["basics"]["name"], "David Parker");
+    assert_eq!(document["basics"]["email"], "david@redkey.io");
+    assert!(document["basics"]["summary"].as_str().unwrap().contains("Warp team"));
+    assert!(document["basics"]["profiles"].as_array().unwrap().iter().any(|p| p["network"] == "GitHub"));
+    assert!(!document["work"].as_array().unwrap().is_empty());
+    assert!(!document["education"].as_array().unwrap().is_empty());
+    assert!(document["projects"].as_array().unwrap().iter().any(|p| p["url"].is_string()));
+    assert!(document["skills"].as_array().unwrap().iter().all(|s| s["level"].is_string()));
+}
+
+#[test]
+fn test_import_writes_content_directory() {
+    let dir = create_output_dir("sample");
+    let imported = import(SAMPLE_RESUME, &dir).unwrap();
+
+    assert!(imported.written.contains(&dir.join("timeline.json")));
+    assert!(imported.skipped.is_empty());
+    assert!(fs::read_to_string(dir.join("about.md")).unwrap().contains("First programmer."));
+
+    let contact = fs::read_to_string(dir.join("contact.md")).unwrap();
+    assert!(contact.contains("**Email:** ada@example.com"));
+    assert!(contact.contains("[github.com/ada](https://github.com/ada)"));
+    assert_eq!(parse_signature(&contact), Some(String::from("Ada Lovelace")));
+
+    let skills: SkillsData = serde_json::from_str(&fs::read_to_string(dir.join("skills.json")).unwrap()).unwrap();
+    assert_eq!(skills.categories.len(), 2);
+    assert_eq!(skills.categories[0].name, "Languages");
+    assert_eq!(skills.categories[0].skills[1].level, 40);
+    assert_eq!(skills.categories[1].name, "Skills");
+
+    let links: ProjectLinks = serde_json::from_str(&fs::read_to_string(dir.join("projects.json")).unwrap()).unwrap();
+    assert_eq!(links.links.len(), 1);
+    assert_eq!(links.links[0].url, "https://example.com/docs");
+
+    // Navigation is described by the keymap-driven footer and help, not by the generated markdown
+    for file in ["welcome.md", "skills.md", "timeline.md"] {
+        let markdown = fs::read_to_string(dir.join(file)).unwrap();
+        assert!(!["↑", "→", "arrow", "j/k", "q to quit"].iter().any(|key| markdown.contains(key)), "{}", file);
+    }
+
+    let events: Vec<TimelineEvent> = serde_json::from_str(&fs::read_to_string(dir.join("timeline.json")).unwrap()).unwrap();
+    let types: Vec<TimelineType> = events.iter().map(|e| e.event_type).collect();
+    assert_eq!(
+        types,
+        vec![TimelineType::Education, TimelineType::Project, TimelineType::Career, TimelineType::Certification]
+    );
+    assert_eq!(events[0].year, 2010);
+    assert_eq!(events[0].start, None);
+    assert_eq!(events[2].start, Some(TimelineDate::Month(YearMonth { year: 2019, month: 3 })));
+    assert_eq!(events[2].end, Some(TimelineDate::Present));
+    assert_eq!(events[2].technologies, Some(vec![String::from("Rust")]));
+    assert_eq!(events[3].end, None);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_export_import_round_trip() {
+    let resume = Resume::load().unwrap();
+    let dir = create_output_dir("round-trip");
+    import(&export(&resume, ExportFormat::JsonResume, &ExportOptions::default()).unwrap(), &dir).unwrap();
+
+    let events: Vec<TimelineEvent> = serde_json::from_str(&fs::read_to_string(dir.join("timeline.json")).unwrap()).unwrap();
+    let mut titles: Vec<&str> = events.iter().map(|e| e.title.as_str()).collect();
+    let mut expected: Vec<&str> = resume.timeline.iter().map(|e| e.title.as_str()).collect();
+    titles.sort();
+    expected.sort();
+    assert_eq!(titles, expected);
+
+    let reimported: JsonResume = serde_json::from_str(&export(&resume, ExportFormat::JsonResume, &ExportOptions::default()).unwrap()).unwrap();
+    assert_eq!(reimported.basics.name, "David Parker");
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_import_errors() {
+    let dir = create_output_dir("errors");
+
+    assert!(matches!(import("{ \"basics\": ", &dir), Err(ContentError::Parse { .. })));
+    assert!(matches!(
+        import(r#"{ "work": [{ "name": "Org", "position": "Role", "startDate": "March" }] }"#, &dir),
+        Err(ContentError::Schema { ref message, .. }) if message.contains("invalid date")
+    ));
+    assert!(matches!(
+        import(r#"{ "work": [{ "name": "Org", "position": "Role", "startDate": "2020-05", "endDate": "2019-01" }] }"#, &dir),
+        Err(ContentError::Schema { ref message, .. }) if message.contains("ends before it starts")
+    ));
+    assert!(!dir.exists());
+}
+
+#[test]
+fn test_import_skips_undated_entries() {
+    let dir = create_output_dir("undated");
+    let imported = import(
+        r#"{ "work": [{ "name": "Org", "position": "Role" }, { "name": "Org", "position": "Lead", "startDate": "2021" }],
+            "certificates": [{ "name": "Cert", "issuer": "" }] }"#,
+        &dir,
+    )
+    .unwrap();
+
+    assert_eq!(imported.skipped, ["'Role' (Org) has no date", "'Cert' has no date"]);
+    let events: Vec<TimelineEvent> = serde_json::from_str(&fs::read_to_string(dir.join("timeline.json")).unwrap()).unwrap();
+    let titles: Vec<&str> = events.iter().map(|e| e.title.as_str()).collect();
+    assert_eq!(titles, ["Lead"]);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_markdown_to_html_uses_tui_parser_options() {
+    let html = markdown_to_html("## Title\n\nSome ~~old~~ **bold** [link](https://example.com)");
+    assert!(html.contains("<h2>Title</h2>"));
+    assert!(html.contains("<del>old</del>"));
+    assert!(html.contains("<strong>bold</strong>"));
+    assert!(html.contains("<a href=\"https://example.com\">link</a>"));
+}
+
+#[test]
+fn test_html_export() {
+    let mut resume = Resume::load().unwrap();
+    resume.timeline.truncate(3);
+    resume.timeline[0].title = String::from("R&D <Lead>");
+
+    let html = to_html(&resume);
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<title>David Parker</title>"));
+    for id in ["about", "skills", "projects", "timeline", "contact"] {
+        assert!(html.contains(&format!("<section id=\"{}\">", id)));
+    }
+    assert!(html.contains("<meter min=\"0\" max=\"100\""));
+    assert_eq!(html.matches("class=\"marker ").count(), 3);
+    assert!(html.contains("R&amp;D &lt;Lead&gt;"));
+    assert!(html.contains("href=\"https://github.com/davidparkercodes/hire-david-parker\""));
+    assert!(!html.contains("<R&D"));
+}
+
+#[test]
+fn test_text_export_wraps_at_width() {
+    let resume = Resume::load().unwrap();
+    let text = to_text(&resume, 60);
+
+    assert!(text.starts_with("David Parker\n============"));
+    for section in ["ABOUT", "SKILLS", "PROJECTS", "TIMELINE", "CONTACT"] {
+        assert!(text.contains(&format!("\n{}\n", section)), "missing {}", section);
+    }
+    assert!(text.contains("] 95%"));
+    assert!(text.contains("[██████████] 100%"));
+    assert!(!text.contains('\x1b'));
+    assert!(text.lines().all(|line| line.chars().count() <= 60 || !line.contains(' ')), "line too long");
+}
+
+#[test]
+fn test_markdown_export() {
+    let resume = Resume::load().unwrap();
+    let markdown = to_markdown(&resume, 72);
+
+    assert!(markdown.starts_with("# David Parker\n"));
+    assert!(markdown.contains("] 95%\n") && markdown.contains("[██████████] 100%"));
+    assert!(markdown.contains("## Timeline"));
+    assert!(markdown.contains("```text\n"));
+    assert!(markdown.contains("[█████████▌") || markdown.contains("[██████████] 100%"));
+    assert!(markdown
+        .lines()
+        .filter(|line| !line.starts_with('#') && line.contains(' '))
+        .all(|line| line.chars().count() <= 72 || line.split_whitespace().count() == 1));
+}
+
+#[test]
+fn test_render_template() {
+    let values = [("name", String::from("Ada")), ("skills", String::from("Rust"))];
+    let rendered = render_template("t", "\\textbf{{\\large {{name}}}} knows {{skills}}", &values).unwrap();
+    assert_eq!(rendered, "\\textbf{{\\large Ada}} knows Rust");
+
+    let error = render_template("custom.tex", "{{unknown}}", &values).unwrap_err();
+    assert_eq!(error.file(), "custom.tex");
+    assert!(error.to_string().contains("{{unknown}}"));
+}
+
+#[test]
+fn test_load_template() {
+    let (name, source) = load_template(latex::TEMPLATES, None).unwrap();
+    assert_eq!(name, "classic");
+    assert!(source.contains("\\documentclass"));
+
+    let (name, _) = load_template(typst::TEMPLATES, Some("COMPACT")).unwrap();
+    assert_eq!(name, "compact");
+
+    let error = load_template(latex::TEMPLATES, Some("fancy")).unwrap_err();
+    assert!(error.to_string().contains("classic, compact"));
+}
+
+#[test]
+fn test_markdown_to_latex() {
+    let latex = markdown_to_latex("### C# & Go\n\nSaved 50% with **bold** [links](https://example.com/a#b)\n\n- one\n- two 🤘");
+    assert!(latex.contains("\\subsection*{C\\# \\& Go}"));
+    assert!(latex.contains("Saved 50\\% with \\textbf{bold} \\href{https://example.com/a\\#b}{links}"));
+    assert!(latex.contains("\\begin{itemize}\n\\item one\n\\item two \n\\end{itemize}"));
+}
+
+#[test]
+fn test_markdown_to_typst() {
+    let typst = markdown_to_typst("### Title\n\nA *b* [link](https://example.com) costs $5 #1\n\n- item");
+    assert!(typst.contains("=== Title"));
+    assert!(typst.contains("A #emph[b] #link(\"https://example.com\")[link] costs \\$5 \\#1"));
+    assert!(typst.contains("- item"));
+    assert_eq!(markdown_to_typst("**x**(y)"), "#strong[x]\\(y)");
+}
+
+#[test]
+fn test_latex_and_typst_exports() {
+    let resume = Resume::load().unwrap();
+
+    let tex = to_latex(&resume, None).unwrap();
+    assert!(tex.starts_with("\\documentclass"));
+    assert!(tex.contains("\\resumecontactlink{GitHub}{github.com/davidparkercodes}{https://github.com/davidparkercodes}"));
+    assert!(tex.contains("\\resumeskill{C\\#}{80}"));
+    assert!(tex.contains("\\resumeevent{2001}{Learned HTML}{Personal}{Education}"));
+    assert!(tex.contains("\\end{document}"));
+    assert!(!tex.contains("{{"));
+
+    let typ = to_typst(&resume, Some("compact")).unwrap();
+    assert!(typ.contains("#skill(\"C#\", 80)"));
+    assert!(typ.contains("#event(year: \"2001\", title: \"Learned HTML\""));
+    assert!(typ.contains("technologies: (\"HTML\",)"));
+    assert!(!typ.contains("{{"));
+}
+
+#[test]
+fn test_custom_template_file() {
+    let path = std::env::temp_dir().join(format!("hdp-template-{}.typ", std::process::id()));
+    fs::write(&path, "= {{name}}\n{{skills}}\n").unwrap();
+
+    let resume = Resume::load().unwrap();
+    let options = ExportOptions { template: Some(path.display().to_string()), ..ExportOptions::default() };
+    let typ = export(&resume, ExportFormat::Typst, &options).unwrap();
+    assert!(typ.starts_with("= David Parker\n#skill-category(\"Skills\")"));
+
+    fs::remove_file(path).unwrap();
+}
@@ -1,8 +1,9 @@
 mod common;
 
-use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent, MouseEventKind};
 use hire_david_parker::tui::state::App;
-use hire_david_parker::tui::models::DisplayMode;
+use hire_david_parker::tui::models::{DisplayMode, TimelineFilter, TimelineType};
+use hire_david_parker::tui::search::SearchLocation;
 
 // Helper function to create a keyboard event
 fn create_key_event(code: KeyCode) -> KeyEvent {
@@ -68,9 +69,9 @@ fn test_menu_key_handling() {
     app.display_mode = DisplayMode::Menu;
     
     app.display_mode = DisplayMode::Menu;
-    app.menu_index = 5;
-    app.handle_key_event(create_key_event(KeyCode::Down)); // Should not go above 5
-    assert_eq!(app.menu_index, 5);
+    app.menu_index = 4;
+    app.handle_key_event(create_key_event(KeyCode::Down)); // Should not go above 4
+    assert_eq!(app.menu_index, 4);
     
     // Test all menu selection options
     app.display_mode = DisplayMode::Menu;
@@ -86,16 +87,11 @@ fn test_menu_key_handling() {
     app.display_mode = DisplayMode::Menu;
     app.menu_index = 3;
     app.handle_key_event(create_key_event(KeyCode::Enter));
-    assert_eq!(app.display_mode, DisplayMode::WhyWarp);
-    
-    app.display_mode = DisplayMode::Menu;
-    app.menu_index = 4;
-    app.handle_key_event(create_key_event(KeyCode::Enter));
     assert_eq!(app.display_mode, DisplayMode::Timeline);
     assert_eq!(app.timeline_index, 0);
     
     app.display_mode = DisplayMode::Menu;
-    app.menu_index = 5;
+    app.menu_index = 4;
     app.handle_key_event(create_key_event(KeyCode::Enter));
     assert_eq!(app.display_mode, DisplayMode::Contact);
 }
@@ -172,15 +168,10 @@ fn test_content_navigation() {
     app.display_mode = DisplayMode::About;
     app.menu_index = 3;
     app.handle_key_event(create_key_event(KeyCode::Enter));
-    assert_eq!(app.display_mode, DisplayMode::WhyWarp);
-    
-    app.display_mode = DisplayMode::About;
-    app.menu_index = 4;
-    app.handle_key_event(create_key_event(KeyCode::Enter));
     assert_eq!(app.display_mode, DisplayMode::Timeline);
     
     app.display_mode = DisplayMode::About;
-    app.menu_index = 5;
+    app.menu_index = 4;
     app.handle_key_event(create_key_event(KeyCode::Enter));
     assert_eq!(app.display_mode, DisplayMode::Contact);
 }
@@ -486,4 +477,384 @@ fn test_leftmost_timeline_navigation() {
     app.handle_key_event(create_key_event(KeyCode::Char('h')));
     assert_eq!(app.display_mode, DisplayMode::Timeline);
     assert_eq!(app.timeline_index, 0); // Should stay at leftmost entry
-}
\ No newline at end of file
+}
+#[test]
+fn test_timeline_filter_navigation() {
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Timeline;
+    
+    // f cycles forward to the first type filter
+    app.handle_key_event(create_key_event(KeyCode::Char('f')));
+    assert_ne!(app.timeline_filter, TimelineFilter::All);
+    
+    // F cycles back to All
+    app.handle_key_event(create_key_event(KeyCode::Char('F')));
+    assert_eq!(app.timeline_filter, TimelineFilter::All);
+    
+    // Right navigation stops at the last filtered event
+    app.timeline_filter = TimelineFilter::Type(TimelineType::Education);
+    let filtered_count = app.get_filtered_events().len();
+    assert!(filtered_count < app.timeline_events.len());
+    
+    app.timeline_index = filtered_count - 1;
+    app.handle_key_event(create_key_event(KeyCode::Right));
+    assert_eq!(app.timeline_index, filtered_count - 1);
+}
+
+#[test]
+fn test_scroll_keys_move_current_pane() {
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Projects;
+    app.menu_index = 2;
+    app.scroll_state_mut(DisplayMode::Projects).set_dimensions(50, 10);
+
+    app.handle_key_event(create_key_event(KeyCode::PageDown));
+    assert_eq!(app.scroll_state(DisplayMode::Projects).offset, 9);
+
+    app.handle_key_event(create_key_event(KeyCode::End));
+    assert_eq!(app.scroll_state(DisplayMode::Projects).offset, 40);
+
+    app.handle_key_event(create_key_event(KeyCode::PageUp));
+    assert_eq!(app.scroll_state(DisplayMode::Projects).offset, 31);
+
+    app.handle_key_event(create_key_event(KeyCode::Home));
+    assert_eq!(app.scroll_state(DisplayMode::Projects).offset, 0);
+
+    // Scrolling never changes the section
+    assert_eq!(app.display_mode, DisplayMode::Projects);
+    assert_eq!(app.scroll_state(DisplayMode::About).offset, 0);
+}
+
+#[test]
+fn test_menu_scrolls_about_preview() {
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Menu;
+    app.scroll_state_mut(DisplayMode::About).set_dimensions(20, 10);
+
+    app.handle_key_event(create_key_event(KeyCode::End));
+    assert_eq!(app.scroll_state(DisplayMode::About).offset, 10);
+    assert_eq!(app.display_mode, DisplayMode::Menu);
+}
+
+#[test]
+fn test_mouse_wheel_scrolls_current_pane() {
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Contact;
+    app.scroll_state_mut(DisplayMode::Contact).set_dimensions(20, 10);
+
+    let wheel = |kind| MouseEvent { kind, column: 40, row: 10, modifiers: KeyModifiers::NONE };
+
+    app.handle_mouse_event(wheel(MouseEventKind::ScrollDown));
+    assert_eq!(app.scroll_state(DisplayMode::Contact).offset, 3);
+
+    app.handle_mouse_event(wheel(MouseEventKind::ScrollUp));
+    app.handle_mouse_event(wheel(MouseEventKind::ScrollUp));
+    assert_eq!(app.scroll_state(DisplayMode::Contact).offset, 0);
+
+    // Panes without scrolling ignore the wheel
+    app.display_mode = DisplayMode::Timeline;
+    app.handle_mouse_event(wheel(MouseEventKind::ScrollDown));
+    assert_eq!(app.scroll_state(DisplayMode::Timeline).offset, 0);
+}
+
+fn type_query(app: &mut App, query: &str) {
+    app.handle_key_event(create_key_event(KeyCode::Char('/')));
+    for c in query.chars() {
+        app.handle_key_event(create_key_event(KeyCode::Char(c)));
+    }
+}
+
+#[test]
+fn test_search_prompt_updates_incrementally() {
+    let mut app = App::new();
+    type_query(&mut app, "warp");
+    assert!(app.search.prompt_open);
+    let matches = app.search.matches.len();
+    assert!(matches > 0);
+
+    // Keys are typed into the prompt instead of navigating
+    app.handle_key_event(create_key_event(KeyCode::Char('q')));
+    assert!(!app.should_exit);
+    assert!(app.search.matches.len() < matches);
+
+    app.handle_key_event(create_key_event(KeyCode::Backspace));
+    assert_eq!(app.search.matches.len(), matches);
+
+    app.handle_key_event(create_key_event(KeyCode::Esc));
+    assert!(!app.search.prompt_open);
+    assert!(app.search.query.is_empty());
+    assert!(app.search.matches.is_empty());
+    assert_eq!(app.display_mode, DisplayMode::About);
+}
+
+#[test]
+fn test_search_enter_jumps_to_match() {
+    let mut app = App::new();
+    let title = app.timeline_events[2].title.clone();
+    type_query(&mut app, &title);
+
+    let position = app.search.matches
+        .iter()
+        .position(|&i| app.search_index.entries[i].location == SearchLocation::TimelineEvent(2))
+        .unwrap();
+    for _ in 0..position {
+        app.handle_key_event(create_key_event(KeyCode::Down));
+    }
+    app.handle_key_event(create_key_event(KeyCode::Enter));
+
+    assert!(!app.search.prompt_open);
+    assert_eq!(app.display_mode, DisplayMode::Timeline);
+    assert_eq!(app.menu_index, 3);
+    assert_eq!(app.timeline_index, 2);
+    assert!(app.timeline_detail_view);
+}
+
+#[test]
+fn test_search_n_cycles_matches() {
+    let mut app = App::new();
+    type_query(&mut app, "warp");
+    app.handle_key_event(create_key_event(KeyCode::Enter));
+    let count = app.search.matches.len();
+    assert!(count > 1);
+    assert_eq!(app.search.selected, 0);
+
+    app.handle_key_event(create_key_event(KeyCode::Char('n')));
+    assert_eq!(app.search.selected, 1);
+    let location = app.selected_search_location().unwrap();
+    assert_eq!(app.display_mode, location.display_mode());
+
+    app.handle_key_event(create_key_event(KeyCode::Char('N')));
+    app.handle_key_event(create_key_event(KeyCode::Char('N')));
+    assert_eq!(app.search.selected, count - 1);
+}
+
+#[test]
+fn test_search_line_match_requests_scroll() {
+    let mut app = App::new();
+    type_query(&mut app, "Warp Commands Navigator");
+    app.handle_key_event(create_key_event(KeyCode::Enter));
+
+    assert_eq!(app.display_mode, DisplayMode::Projects);
+    assert_eq!(app.menu_index, 2);
+    assert!(matches!(app.search.pending_scroll, Some((DisplayMode::Projects, _))));
+}
+
+fn create_ctrl_key_event(c: char) -> KeyEvent {
+    KeyEvent {
+        code: KeyCode::Char(c),
+        modifiers: KeyModifiers::CONTROL,
+        kind: KeyEventKind::Press,
+        state: crossterm::event::KeyEventState::NONE,
+    }
+}
+
+fn run_palette_query(app: &mut App, query: &str) {
+    app.handle_key_event(create_ctrl_key_event('p'));
+    for c in query.chars() {
+        app.handle_key_event(create_key_event(KeyCode::Char(c)));
+    }
+    app.handle_key_event(create_key_event(KeyCode::Enter));
+}
+
+#[test]
+fn test_palette_opens_filters_and_closes() {
+    let mut app = App::new();
+    app.handle_key_event(create_ctrl_key_event('p'));
+    assert!(app.palette.open);
+    assert_eq!(app.palette.matches.len(), app.palette_entries.len());
+
+    app.handle_key_event(create_key_event(KeyCode::Down));
+    assert_eq!(app.palette.selected, 1);
+    app.handle_key_event(create_ctrl_key_event('p'));
+    assert_eq!(app.palette.selected, 0);
+
+    // Typed keys filter instead of running their usual bindings
+    app.handle_key_event(create_key_event(KeyCode::Char('q')));
+    assert!(!app.should_exit);
+    assert!(app.palette.matches.len() < app.palette_entries.len());
+
+    app.handle_key_event(create_key_event(KeyCode::Esc));
+    assert!(!app.palette.open);
+    assert_eq!(app.display_mode, DisplayMode::About);
+}
+
+#[test]
+fn test_palette_jumps_to_targets() {
+    let mut app = App::new();
+    run_palette_query(&mut app, "go to contact");
+    assert_eq!(app.display_mode, DisplayMode::Contact);
+    assert_eq!(app.menu_index, 4);
+
+    let event = app.timeline_events[3].clone();
+    app.timeline_filter = TimelineFilter::Type(TimelineType::Education);
+    run_palette_query(&mut app, &format!("timeline {} {}", event.year, event.title));
+    assert_eq!(app.display_mode, DisplayMode::Timeline);
+    assert_eq!(app.timeline_filter, TimelineFilter::All);
+    assert_eq!(app.timeline_index, 3);
+    assert!(app.timeline_detail_view);
+
+    let category = app.skills_data.categories.len() - 1;
+    let name = app.skills_data.categories[category].name.clone();
+    run_palette_query(&mut app, &format!("skills: {}", name));
+    assert_eq!(app.display_mode, DisplayMode::SkillsVisual);
+    assert_eq!(app.skill_category_index, category);
+
+    let link = app.project_links[1].text.clone();
+    run_palette_query(&mut app, &format!("project: {}", link));
+    assert_eq!(app.display_mode, DisplayMode::ProjectLinks);
+    assert_eq!(app.link_index, 1);
+}
+
+#[test]
+fn test_palette_copy_email_requests_clipboard() {
+    let mut app = App::new();
+    run_palette_query(&mut app, "copy email");
+    assert_eq!(app.clipboard_request.as_deref(), Some("david@redkey.io"));
+}
+
+#[test]
+fn test_tab_cycles_inline_link_focus() {
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Contact;
+    app.menu_index = 4;
+    let links = app.section_links(DisplayMode::Contact);
+    assert!(links.len() >= 2);
+
+    app.handle_key_event(create_key_event(KeyCode::Tab));
+    assert_eq!(app.focused_link().unwrap().url, links[0].url);
+
+    app.handle_key_event(create_key_event(KeyCode::Tab));
+    assert_eq!(app.focused_link().unwrap().url, links[1].url);
+
+    app.handle_key_event(create_key_event(KeyCode::BackTab));
+    app.handle_key_event(create_key_event(KeyCode::BackTab));
+    assert_eq!(app.focused_link().unwrap().url, links[links.len() - 1].url);
+
+    // Focus belongs to the section it was made in
+    app.handle_key_event(create_key_event(KeyCode::Up));
+    assert_eq!(app.display_mode, DisplayMode::Timeline);
+    assert!(app.focused_link().is_none());
+}
+
+#[test]
+fn test_tab_without_links_does_nothing() {
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Timeline;
+    app.handle_key_event(create_key_event(KeyCode::Tab));
+    assert!(app.link_focus.is_none());
+}
+
+#[test]
+fn test_yank_copies_focused_link() {
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Contact;
+    app.menu_index = 4;
+    let links = app.section_links(DisplayMode::Contact);
+
+    app.handle_key_event(create_key_event(KeyCode::Tab));
+    app.handle_key_event(create_key_event(KeyCode::Char('y')));
+    assert_eq!(app.clipboard_request.as_deref(), Some(links[0].url.as_str()));
+    let status = app.status.clone().unwrap();
+    assert!(!status.is_error);
+    assert!(status.text.contains(&links[0].url));
+}
+
+#[test]
+fn test_yank_copies_selected_project_link() {
+    let mut app = App::new();
+    app.display_mode = DisplayMode::ProjectLinks;
+    app.link_index = 1;
+
+    app.handle_key_event(create_key_event(KeyCode::Char('y')));
+    assert_eq!(app.clipboard_request.as_deref(), Some(app.project_links[1].url.as_str()));
+}
+
+#[test]
+fn test_yank_on_contact_picks_a_field() {
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Contact;
+    app.menu_index = 4;
+
+    app.handle_key_event(create_key_event(KeyCode::Char('y')));
+    assert!(app.palette.open);
+    let copy_entries = app.palette_entries.iter().filter(|entry| entry.label.starts_with("Copy ")).count();
+    assert!(copy_entries >= 2);
+    for &entry in &app.palette.matches[..copy_entries] {
+        assert!(app.palette_entries[entry].label.starts_with("Copy "));
+    }
+
+    app.handle_key_event(create_key_event(KeyCode::Char('p')));
+    app.handle_key_event(create_key_event(KeyCode::Char('h')));
+    app.handle_key_event(create_key_event(KeyCode::Enter));
+    assert_eq!(app.clipboard_request.as_deref(), Some("(757) 230-3795"));
+    assert!(app.status.unwrap().text.contains("(757) 230-3795"));
+}
+
+#[test]
+fn test_yank_without_target_reports_error() {
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Timeline;
+
+    app.handle_key_event(create_key_event(KeyCode::Char('y')));
+    assert!(app.clipboard_request.is_none());
+    assert!(app.status.unwrap().is_error);
+}
+
+#[test]
+fn test_t_cycles_theme() {
+    let mut app = App::new();
+    assert_eq!(app.theme().name, "dark");
+
+    app.handle_key_event(create_key_event(KeyCode::Char('t')));
+    assert_eq!(app.theme().name, "light");
+    assert_eq!(app.status.clone().unwrap().text, "Theme: light");
+
+    for _ in 1..app.themes.len() {
+        app.handle_key_event(create_key_event(KeyCode::Char('t')));
+    }
+    assert_eq!(app.theme().name, "dark");
+}
+
+#[test]
+fn test_question_mark_toggles_help() {
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Timeline;
+
+    app.handle_key_event(create_key_event(KeyCode::Char('?')));
+    assert!(app.help.is_some());
+
+    // Navigation keys scroll the overlay instead of the timeline
+    app.handle_key_event(create_key_event(KeyCode::Right));
+    assert_eq!(app.timeline_index, 0);
+
+    app.handle_key_event(create_key_event(KeyCode::Char('q')));
+    assert!(app.help.is_none());
+    assert!(!app.should_exit);
+
+    app.handle_key_event(create_key_event(KeyCode::Char('?')));
+    app.handle_key_event(create_key_event(KeyCode::Esc));
+    assert!(app.help.is_none());
+    assert_eq!(app.display_mode, DisplayMode::Timeline);
+}
+
+#[test]
+fn test_help_lists_bindings_of_current_screen() {
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Timeline;
+
+    let sections = app.help_sections();
+    let categories: Vec<&str> = sections.iter().map(|(category, _)| category.label()).collect();
+    assert_eq!(categories, ["Navigation", "Search", "General"]);
+
+    let entries: Vec<(String, &str)> = sections.into_iter().flat_map(|(_, entries)| entries).collect();
+    assert!(entries.contains(&(String::from("Enter"), "Show the event details")));
+    assert!(entries.contains(&(String::from("Esc/Backspace"), "Return to the menu")));
+    assert!(entries.contains(&(String::from("f"), "Next event filter")));
+    assert!(!entries.iter().any(|(_, description)| description.contains("Scroll")));
+
+    app.display_mode = DisplayMode::Contact;
+    app.keymap = hire_david_parker::tui::keymap::Keymap::from_toml("[content]\ncopy = \"c\"\n").unwrap();
+    let entries: Vec<(String, &str)> = app.help_sections().into_iter().flat_map(|(_, entries)| entries).collect();
+    assert!(entries.contains(&(String::from("c"), "Copy a contact detail")));
+    assert!(entries.contains(&(String::from("PgDn"), "Scroll down a page")));
+}
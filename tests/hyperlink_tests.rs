@@ -0,0 +1,56 @@
+mod common;
+
+use std::collections::HashMap;
+
+use hire_david_parker::tui::hyperlink::{self, osc8_sequence, HYPERLINKS_ENV};
+use ratatui::{backend::TestBackend, buffer::Buffer, layout::Rect, style::{Color, Style}};
+
+fn supported(vars: &[(&str, &str)]) -> bool {
+    let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    hyperlink::supported(|name| vars.get(name).cloned())
+}
+
+#[test]
+fn test_detects_known_terminals() {
+    assert!(supported(&[("TERM", "xterm-256color"), ("TERM_PROGRAM", "WezTerm")]));
+    assert!(supported(&[("TERM", "xterm-kitty")]));
+    assert!(supported(&[("TERM", "xterm-256color"), ("VTE_VERSION", "7600")]));
+    assert!(supported(&[("TERM", "xterm-256color"), ("WT_SESSION", "1")]));
+}
+
+#[test]
+fn test_unknown_and_dumb_terminals_are_unsupported() {
+    assert!(!supported(&[]));
+    assert!(!supported(&[("TERM", "xterm-256color")]));
+    assert!(!supported(&[("TERM", "xterm-256color"), ("VTE_VERSION", "4200")]));
+    assert!(!supported(&[("TERM", "dumb"), ("TERM_PROGRAM", "WezTerm")]));
+}
+
+#[test]
+fn test_environment_override() {
+    assert!(!supported(&[(HYPERLINKS_ENV, "0"), ("TERM", "xterm-kitty")]));
+    assert!(!supported(&[(HYPERLINKS_ENV, "off"), ("TERM", "xterm-kitty")]));
+    assert!(supported(&[(HYPERLINKS_ENV, "1"), ("TERM", "xterm-256color")]));
+}
+
+#[test]
+fn test_osc8_sequence_strips_control_characters() {
+    assert_eq!(osc8_sequence("https://example.com"), "\x1b]8;;https://example.com\x1b\\");
+    assert_eq!(osc8_sequence("https://example.com/\x1b\x07x"), "\x1b]8;;https://example.com/x\x1b\\");
+    assert_eq!(osc8_sequence(""), "\x1b]8;;\x1b\\");
+}
+
+#[test]
+fn test_draw_wraps_target_cells() {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 2));
+    buffer.set_string(2, 1, "Go 日本", Style::default().fg(Color::Cyan));
+    let mut backend = TestBackend::new(20, 2);
+
+    hyperlink::draw(&mut backend, &buffer, &[(Rect::new(2, 1, 7, 1), "https://example.com".to_string())]).unwrap();
+    let drawn = backend.buffer();
+    assert_eq!(drawn[(2, 1)].symbol(), format!("{}G", osc8_sequence("https://example.com")));
+    assert_eq!(drawn[(2, 1)].fg, Color::Cyan);
+    assert_eq!(drawn[(5, 1)].symbol(), "日");
+    assert_eq!(drawn[(7, 1)].symbol(), format!("本{}", osc8_sequence("")));
+    assert_eq!(drawn[(0, 1)].symbol(), " ");
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use std::fs;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use hire_david_parker::tui::keymap::{Action, Hint, KeyBinding, Keymap, KeymapError, Scope};
+use hire_david_parker::tui::models::DisplayMode;
+use hire_david_parker::tui::state::App;
+
+// Helper function to create a key press with modifiers
+fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+    KeyEvent::new(code, modifiers)
+}
+
+#[test]
+fn test_parse_key_bindings() {
+    assert_eq!("q".parse::<KeyBinding>().unwrap(), KeyBinding::from(KeyCode::Char('q')));
+    assert_eq!("ctrl-p".parse::<KeyBinding>().unwrap(), KeyBinding::new(KeyCode::Char('p'), KeyModifiers::CONTROL));
+    assert_eq!("Ctrl-P".parse::<KeyBinding>().unwrap(), KeyBinding::new(KeyCode::Char('p'), KeyModifiers::CONTROL));
+    assert_eq!("shift-n".parse::<KeyBinding>().unwrap(), KeyBinding::from(KeyCode::Char('N')));
+    assert_eq!("shift-tab".parse::<KeyBinding>().unwrap(), KeyBinding::from(KeyCode::BackTab));
+    assert_eq!("pgdn".parse::<KeyBinding>().unwrap(), KeyBinding::from(KeyCode::PageDown));
+    assert_eq!("space".parse::<KeyBinding>().unwrap(), KeyBinding::from(KeyCode::Char(' ')));
+    assert_eq!("f".parse::<KeyBinding>().unwrap(), KeyBinding::from(KeyCode::Char('f')));
+    assert_eq!("F5".parse::<KeyBinding>().unwrap(), KeyBinding::from(KeyCode::F(5)));
+    assert!("hyper-x".parse::<KeyBinding>().is_err());
+    assert!("".parse::<KeyBinding>().is_err());
+}
+
+#[test]
+fn test_key_binding_display() {
+    let names: Vec<String> = ["ctrl-p", "up", "shift-tab", "pgup", "esc", "N"]
+        .iter()
+        .map(|name| name.parse::<KeyBinding>().unwrap().to_string())
+        .collect();
+    assert_eq!(names, ["Ctrl-P", "↑", "Shift-Tab", "PgUp", "Esc", "N"]);
+}
+
+#[test]
+fn test_default_keymap_prefers_screen_bindings() {
+    let keymap = Keymap::default();
+    let esc = key(KeyCode::Esc, KeyModifiers::NONE);
+    assert_eq!(keymap.action(Scope::Menu, &esc), Some(Action::Quit));
+    assert_eq!(keymap.action(Scope::Content, &esc), Some(Action::Back));
+    assert_eq!(keymap.action(Scope::Timeline, &key(KeyCode::Char('F'), KeyModifiers::SHIFT)), Some(Action::PreviousFilter));
+    assert_eq!(keymap.action(Scope::Menu, &key(KeyCode::Char('f'), KeyModifiers::NONE)), None);
+    assert_eq!(keymap.action(Scope::Menu, &key(KeyCode::Char('p'), KeyModifiers::CONTROL)), Some(Action::CommandPalette));
+    assert_eq!(keymap.action(Scope::Menu, &key(KeyCode::Char('p'), KeyModifiers::NONE)), None);
+}
+
+#[test]
+fn test_default_footers_match_the_documented_keys() {
+    let keymap = Keymap::default();
+    let hints = [
+        Hint::new("Quit", &[Action::Quit]),
+        Hint::new("Up", &[Action::Up]).with_keys(2),
+        Hint::new("Filter", &[Action::NextFilter, Action::PreviousFilter]),
+        Hint::new("Return to Menu", &[Action::Back]),
+    ];
+    assert_eq!(keymap.footer(Scope::Timeline, &hints), "q: Quit | ↑/k: Up | f/F: Filter | Esc: Return to Menu");
+    assert_eq!(keymap.footer(Scope::Content, &hints), "q: Quit | ↑/k: Up | Esc: Return to Menu");
+}
+
+#[test]
+fn test_toml_keymap_replaces_and_steals_keys() {
+    let keymap = Keymap::from_toml(
+        "[global]\nquit = [\"x\", \"ctrl-q\"]\nup = \"w\"\n\n[timeline]\nnext_filter = \"q\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(keymap.action(Scope::Content, &key(KeyCode::Char('x'), KeyModifiers::NONE)), Some(Action::Quit));
+    assert_eq!(keymap.action(Scope::Content, &key(KeyCode::Char('q'), KeyModifiers::CONTROL)), Some(Action::Quit));
+    assert_eq!(keymap.action(Scope::Content, &key(KeyCode::Char('q'), KeyModifiers::NONE)), None);
+    assert_eq!(keymap.action(Scope::Content, &key(KeyCode::Char('k'), KeyModifiers::NONE)), None);
+    assert_eq!(keymap.action(Scope::Content, &key(KeyCode::Char('w'), KeyModifiers::NONE)), Some(Action::Up));
+    assert_eq!(keymap.action(Scope::Timeline, &key(KeyCode::Char('q'), KeyModifiers::NONE)), Some(Action::NextFilter));
+    assert_eq!(keymap.action(Scope::Timeline, &key(KeyCode::Char('f'), KeyModifiers::NONE)), None);
+    assert_eq!(keymap.action(Scope::Timeline, &key(KeyCode::Down, KeyModifiers::NONE)), Some(Action::Down));
+
+    assert_eq!(keymap.hint(Scope::Content, &Hint::new("Quit", &[Action::Quit]).with_keys(2)).unwrap(), "x/Ctrl-Q: Quit");
+    assert!(keymap.keys(Scope::Timeline, Action::Quit).contains(&KeyBinding::from(KeyCode::Char('x'))));
+}
+
+#[test]
+fn test_invalid_toml_keymaps() {
+    for source in ["[global]\nfly = \"x\"", "[global]\nquit = \"hyper-x\"", "[sidebar]\nquit = \"x\"", "[global]\nquit = 3", "not toml"] {
+        assert!(matches!(Keymap::from_toml(source), Err(KeymapError::Parse(_))), "{}", source);
+    }
+
+    let missing = std::env::temp_dir().join(format!("hdp-missing-keys-{}.toml", std::process::id()));
+    assert!(matches!(Keymap::load(&missing), Err(KeymapError::Io { .. })));
+}
+
+#[test]
+fn test_load_keymap_file() {
+    let path = std::env::temp_dir().join(format!("hdp-keys-{}.toml", std::process::id()));
+    fs::write(&path, "[menu]\nselect = \"space\"\n").unwrap();
+    let keymap = Keymap::load(&path).unwrap();
+    let _ = fs::remove_file(&path);
+
+    assert_eq!(keymap.action(Scope::Menu, &key(KeyCode::Char(' '), KeyModifiers::NONE)), Some(Action::Select));
+    assert_eq!(keymap.action(Scope::Menu, &key(KeyCode::Enter, KeyModifiers::NONE)), None);
+    assert_eq!(keymap.action(Scope::Content, &key(KeyCode::Enter, KeyModifiers::NONE)), Some(Action::Select));
+    assert_eq!(keymap.keys(Scope::Menu, Action::Select), ["space".parse::<KeyBinding>().unwrap()]);
+}
+
+#[test]
+fn test_app_uses_custom_keymap() {
+    let mut app = App::new();
+    app.keymap = Keymap::from_toml("[global]\ndown = \"s\"\nquit = \"x\"\n").unwrap();
+    app.display_mode = DisplayMode::Menu;
+    app.skip_auto_switch = true;
+
+    app.handle_key_event(key(KeyCode::Char('j'), KeyModifiers::NONE));
+    assert_eq!(app.menu_index, 0);
+
+    app.handle_key_event(key(KeyCode::Char('s'), KeyModifiers::NONE));
+    assert_eq!(app.menu_index, 1);
+
+    app.handle_key_event(key(KeyCode::Char('q'), KeyModifiers::NONE));
+    assert!(!app.should_exit);
+    app.handle_key_event(key(KeyCode::Char('x'), KeyModifiers::NONE));
+    assert!(app.should_exit);
+}
@@ -1,7 +1,8 @@
 mod common;
 
-use hire_david_parker::tui::markdown::parse_markdown;
-use ratatui::style::Modifier;
+use hire_david_parker::tui::markdown::{parse_markdown, style_columns, wrapped_position};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
 
 #[test]
 fn test_parse_basic_markdown() {
@@ -238,4 +239,61 @@ fn test_paragraph_spacing() {
     assert_eq!(paragraph_texts[0], "Paragraph 1.");
     assert_eq!(paragraph_texts[1], "Paragraph 2.");
     assert_eq!(paragraph_texts[2], "Paragraph 3.");
-}
\ No newline at end of file
+}
+#[test]
+fn test_link_columns_use_display_width() {
+    let (_, links) = parse_markdown("café → [link](https://example.com)");
+    assert_eq!(links[0].start_column, 7);
+    assert_eq!(links[0].end_column, 11);
+
+    let (_, links) = parse_markdown("- **GitHub:** [github.com/例](https://github.com)");
+    assert_eq!(links[0].start_column, 10);
+    assert_eq!(links[0].end_column, 10 + "github.com/".len() + 2);
+}
+
+#[test]
+fn test_link_columns_reset_after_heading() {
+    let (text, links) = parse_markdown("# A heading\n\n[link](https://example.com) after");
+    assert_eq!(links[0].start_column, 0);
+    let line: String = text.lines[links[0].line].spans.iter().map(|span| span.content.as_ref()).collect();
+    assert!(line.starts_with("link"));
+}
+
+#[test]
+fn test_styled_link_is_one_link() {
+    let (_, links) = parse_markdown("See [**bold** and `code`](https://example.com).");
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].text, "bold and code");
+    assert_eq!(links[0].start_column, 4);
+    assert_eq!(links[0].end_column, 17);
+}
+
+#[test]
+fn test_wrapped_position() {
+    let line = Line::from("one two three four");
+    assert_eq!(wrapped_position(&line, 0, 40), (0, 0));
+    assert_eq!(wrapped_position(&line, 8, 40), (0, 8));
+
+    // "three" does not fit after "one two " in 10 columns
+    assert_eq!(wrapped_position(&line, 8, 10), (1, 0));
+    assert_eq!(wrapped_position(&line, 14, 10), (1, 6));
+
+    // Words longer than the width are broken
+    let long = Line::from("abcdefghij");
+    assert_eq!(wrapped_position(&long, 7, 4), (1, 3));
+
+    // Wide characters count as two columns
+    let wide = Line::from("例例 abc");
+    assert_eq!(wrapped_position(&wide, 5, 40), (0, 5));
+}
+
+#[test]
+fn test_style_columns_splits_spans() {
+    let mut line = Line::from(vec![Span::raw("• "), Span::raw("héllo world")]);
+    style_columns(&mut line, 4, 9, Style::default().bg(Color::Yellow));
+
+    let contents: Vec<&str> = line.spans.iter().map(|span| span.content.as_ref()).collect();
+    assert_eq!(contents, vec!["• ", "hé", "llo w", "orld"]);
+    assert_eq!(line.spans[2].style.bg, Some(Color::Yellow));
+    assert_eq!(line.spans[1].style.bg, None);
+}
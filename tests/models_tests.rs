@@ -0,0 +1,129 @@
+mod common;
+
+use hire_david_parker::tui::models::{format_duration, timeline_span_lanes, ScrollState, TimelineDate, TimelineEvent, TimelineFilter, TimelineType, YearMonth};
+
+// Helper function to create a timeline event with an optional date range
+fn create_event(year: u16, start: Option<&str>, end: Option<&str>) -> TimelineEvent {
+    TimelineEvent {
+        year,
+        event_type: TimelineType::Career,
+        title: "Title".to_string(),
+        organization: "Org".to_string(),
+        description: "Description".to_string(),
+        highlights: None,
+        technologies: None,
+        start: start.map(|s| TimelineDate::try_from(s.to_string()).unwrap()),
+        end: end.map(|s| TimelineDate::try_from(s.to_string()).unwrap()),
+    }
+}
+
+#[test]
+fn test_timeline_date_parsing() {
+    assert_eq!(
+        TimelineDate::try_from("2021-03".to_string()),
+        Ok(TimelineDate::Month(YearMonth { year: 2021, month: 3 }))
+    );
+    assert_eq!(TimelineDate::try_from("present".to_string()), Ok(TimelineDate::Present));
+    assert_eq!(TimelineDate::try_from("Present".to_string()), Ok(TimelineDate::Present));
+    
+    // Invalid dates are rejected
+    assert!(TimelineDate::try_from("2021".to_string()).is_err());
+    assert!(TimelineDate::try_from("2021-13".to_string()).is_err());
+    assert!(TimelineDate::try_from("21-03".to_string()).is_err());
+}
+
+#[test]
+fn test_timeline_date_serde_round_trip() {
+    let json = r#"{"year": 2021, "type": "career", "title": "T", "organization": "O", "description": "D",
+        "highlights": null, "technologies": null, "start": "2021-03", "end": "present"}"#;
+    let event: TimelineEvent = serde_json::from_str(json).unwrap();
+    
+    assert_eq!(event.start, Some(TimelineDate::Month(YearMonth { year: 2021, month: 3 })));
+    assert_eq!(event.end, Some(TimelineDate::Present));
+    
+    let serialized = serde_json::to_string(&event).unwrap();
+    assert!(serialized.contains(r#""start":"2021-03""#));
+    assert!(serialized.contains(r#""end":"present""#));
+}
+
+#[test]
+fn test_timeline_event_span_and_duration() {
+    let today = YearMonth { year: 2024, month: 6 };
+    
+    let closed = create_event(2021, Some("2021-03"), Some("2022-02"));
+    assert_eq!(closed.duration_months(today), Some(12));
+    assert_eq!(closed.year_range(today), (2021, 2022));
+    assert_eq!(closed.date_range_label(), Some("Mar 2021 - Feb 2022".to_string()));
+    
+    let ongoing = create_event(2023, Some("2023-01"), Some("present"));
+    assert_eq!(ongoing.duration_months(today), Some(18));
+    assert_eq!(ongoing.date_range_label(), Some("Jan 2023 - Present".to_string()));
+    
+    let year_only = create_event(2015, None, None);
+    assert_eq!(year_only.span(today), None);
+    assert_eq!(year_only.year_range(today), (2015, 2015));
+    assert_eq!(year_only.date_range_label(), None);
+}
+
+#[test]
+fn test_timeline_span_lanes_keep_overlapping_spans_apart() {
+    let today = YearMonth { year: 2024, month: 6 };
+    let first = create_event(2018, Some("2018-01"), Some("2020-12"));
+    let overlapping = create_event(2019, Some("2019-06"), Some("2021-06"));
+    let later = create_event(2021, Some("2021-01"), Some("2022-01"));
+    let year_only = create_event(2020, None, None);
+
+    let (lanes, lane_count) = timeline_span_lanes(&[&first, &overlapping, &later, &year_only], today);
+    assert_eq!(lanes, [Some(0), Some(1), Some(0), None]);
+    assert_eq!(lane_count, 2);
+}
+
+#[test]
+fn test_year_range_filter_matches_overlapping_spans() {
+    let event = create_event(2018, Some("2018-06"), Some("2021-01"));
+    
+    assert!(TimelineFilter::YearRange(2020, 2029).matches(&event));
+    assert!(TimelineFilter::YearRange(2010, 2019).matches(&event));
+    assert!(!TimelineFilter::YearRange(2000, 2009).matches(&event));
+}
+
+#[test]
+fn test_format_duration() {
+    assert_eq!(format_duration(1), "1 mo");
+    assert_eq!(format_duration(8), "8 mos");
+    assert_eq!(format_duration(12), "1 yr");
+    assert_eq!(format_duration(27), "2 yrs 3 mos");
+}
+
+#[test]
+fn test_year_month_today_is_plausible() {
+    let today = YearMonth::today();
+    assert!(today.year >= 2024);
+    assert!((1..=12).contains(&today.month));
+}
+
+#[test]
+fn test_scroll_state_clamps_to_content() {
+    let mut scroll = ScrollState::default();
+    scroll.set_dimensions(30, 10);
+    assert_eq!(scroll.max_offset(), 20);
+    assert!(!scroll.has_more_above());
+    assert!(scroll.has_more_below());
+
+    scroll.scroll_by(scroll.page_size());
+    assert_eq!(scroll.offset, 9);
+
+    scroll.scroll_by(100);
+    assert_eq!(scroll.offset, 20);
+    assert!(scroll.has_more_above());
+    assert!(!scroll.has_more_below());
+
+    scroll.scroll_by(-100);
+    assert_eq!(scroll.offset, 0);
+
+    // Growing the viewport past the content pulls the offset back
+    scroll.offset = 20;
+    scroll.set_dimensions(30, 40);
+    assert_eq!(scroll.offset, 0);
+    assert_eq!(scroll.max_offset(), 0);
+}
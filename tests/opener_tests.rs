@@ -0,0 +1,93 @@
+mod common;
+
+use hire_david_parker::tui::models::StatusMessage;
+use hire_david_parker::tui::opener::UrlOpener;
+use hire_david_parker::tui::state::App;
+
+#[test]
+fn test_flag_overrides_environment() {
+    let opener = UrlOpener::resolve(Some("firefox --new-tab".to_string()));
+    assert_eq!(opener.commands(), ["firefox --new-tab"]);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_browser_list_is_split_on_colons() {
+    let opener = UrlOpener::resolve(Some("missing-browser:firefox %s".to_string()));
+    assert_eq!(opener.commands(), ["missing-browser", "firefox %s"]);
+}
+
+#[test]
+fn test_blank_flag_falls_back() {
+    let opener = UrlOpener::resolve(Some("  ".to_string()));
+    assert!(!opener.commands().is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_open_tries_commands_in_order() {
+    let opener = UrlOpener::new(["definitely-not-a-browser", "true %s"]);
+    assert_eq!(opener.open("https://example.com"), Ok("true".to_string()));
+}
+
+#[test]
+fn test_open_reports_failures() {
+    let error = UrlOpener::new(["definitely-not-a-browser"]).open("https://example.com").unwrap_err();
+    assert!(error.contains("definitely-not-a-browser"));
+
+    let error = UrlOpener::new(Vec::<String>::new()).open("https://example.com").unwrap_err();
+    assert!(error.contains("no browser"));
+}
+
+#[test]
+fn test_failed_open_copies_url_and_sets_status() {
+    let mut app = App::new();
+    app.url_opener = UrlOpener::new(["definitely-not-a-browser"]);
+
+    app.open_url("https://example.com");
+    let status = app.status.clone().unwrap();
+    assert!(status.is_error);
+    assert!(status.text.contains("https://example.com"));
+    assert_eq!(app.clipboard_request.as_deref(), Some("https://example.com"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_successful_open_sets_status() {
+    let mut app = App::new();
+    app.url_opener = UrlOpener::new(["true"]);
+
+    app.open_url("https://example.com");
+    let status = app.status.clone().unwrap();
+    assert!(!status.is_error);
+    assert!(app.clipboard_request.is_none());
+}
+
+#[test]
+fn test_status_expires() {
+    let mut app = App::new();
+    app.set_status("Hello", false);
+    let shown_at = app.status.as_ref().unwrap().shown_at;
+
+    app.expire_status(shown_at);
+    assert!(app.status.is_some());
+
+    app.expire_status(shown_at + StatusMessage::DURATION);
+    assert!(app.status.is_none());
+}
+
+#[test]
+fn test_enter_on_project_link_reports_in_tui() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use hire_david_parker::tui::models::DisplayMode;
+
+    let mut app = App::new();
+    app.url_opener = UrlOpener::new(["definitely-not-a-browser"]);
+    app.display_mode = DisplayMode::ProjectLinks;
+    app.link_index = 1;
+
+    app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    let url = app.project_links[1].url.clone();
+    assert!(app.status.as_ref().unwrap().text.contains(&url));
+    assert_eq!(app.clipboard_request, Some(url));
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use hire_david_parker::tui::palette::{filter_entries, fuzzy_score, PaletteAction};
+use hire_david_parker::tui::state::App;
+
+#[test]
+fn test_fuzzy_score_matches_subsequences() {
+    assert!(fuzzy_score("tl", "Go to Timeline").is_some());
+    assert!(fuzzy_score("GOTO", "Go to Timeline").is_some());
+    assert!(fuzzy_score("go to", "Go to Timeline").is_some());
+    assert!(fuzzy_score("xyz", "Go to Timeline").is_none());
+    assert!(fuzzy_score("enil", "Go to Timeline").is_none());
+    assert_eq!(fuzzy_score("", "anything"), Some(0));
+}
+
+#[test]
+fn test_fuzzy_score_prefers_contiguous_word_matches() {
+    let contiguous = fuzzy_score("time", "Go to Timeline").unwrap();
+    let scattered = fuzzy_score("time", "The Interactive Medium").unwrap();
+    assert!(contiguous > scattered);
+}
+
+#[test]
+fn test_palette_lists_every_target() {
+    let app = App::new();
+    let entries = &app.palette_entries;
+
+    for (_, mode) in app.screens.entries() {
+        assert!(entries.iter().any(|entry| entry.action == PaletteAction::Screen(mode)));
+    }
+    for i in 0..app.timeline_events.len() {
+        assert!(entries.iter().any(|entry| entry.action == PaletteAction::TimelineEvent(i)));
+    }
+    for i in 0..app.skills_data.categories.len() {
+        assert!(entries.iter().any(|entry| entry.action == PaletteAction::SkillCategory(i)));
+    }
+    for (i, link) in app.project_links.iter().enumerate() {
+        assert!(entries.iter().any(|entry| entry.action == PaletteAction::ProjectLink(i)));
+        assert!(entries.iter().any(|entry| entry.action == PaletteAction::OpenUrl(link.url.clone())));
+    }
+    assert!(entries.iter().any(|entry| entry.label.starts_with("Copy email")
+        && entry.action == PaletteAction::Copy("david@redkey.io".to_string())));
+}
+
+#[test]
+fn test_filter_entries_ranks_best_match_first() {
+    let app = App::new();
+    let matches = filter_entries(&app.palette_entries, "copy email");
+    assert!(!matches.is_empty());
+    assert!(app.palette_entries[matches[0]].label.starts_with("Copy email"));
+
+    // An empty query keeps every entry in its original order
+    let all = filter_entries(&app.palette_entries, "");
+    assert_eq!(all, (0..app.palette_entries.len()).collect::<Vec<_>>());
+}
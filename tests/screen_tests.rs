@@ -0,0 +1,186 @@
+mod common;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use hire_david_parker::tui::keymap::{Action, Hint, Scope};
+use hire_david_parker::tui::models::DisplayMode;
+use hire_david_parker::tui::palette::PaletteAction;
+use hire_david_parker::tui::screen::{AboutScreen, Screen, ScreenRegistry, TimelineScreen};
+use hire_david_parker::tui::state::App;
+use hire_david_parker::tui::ui;
+use ratatui::{backend::TestBackend, layout::Rect, widgets::{Block, Borders, Paragraph}, Frame, Terminal};
+
+// A section a team could add without touching the built-in screens
+struct TalksScreen;
+
+impl Screen for TalksScreen {
+    fn title(&self) -> &str {
+        "Talks"
+    }
+
+    fn render(&self, f: &mut Frame, _app: &mut App, area: Rect) {
+        let talks = Paragraph::new("Rust in the Terminal, 2024").block(Block::default().title("Talks").borders(Borders::ALL));
+        f.render_widget(talks, area);
+    }
+
+    fn handle_action(&self, app: &mut App, action: Action) {
+        app.handle_content_action(action);
+    }
+
+    fn footer_hints(&self, _app: &App) -> Vec<Hint> {
+        vec![Hint::QUIT, Hint::new("Talks", &[Action::Select]), Hint::RETURN]
+    }
+
+    fn actions(&self, _app: &App) -> Vec<(Action, &'static str)> {
+        vec![(Action::Select, "Play the selected talk"), (Action::Back, "Return to the menu")]
+    }
+}
+
+fn press(app: &mut App, code: KeyCode) {
+    app.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+}
+
+fn render_to_string(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+    terminal.draw(|f| ui::render(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let mut result = String::new();
+    for row in 0..buffer.area.height {
+        for col in 0..buffer.area.width {
+            result.push(buffer[(col, row)].symbol().chars().next().unwrap_or(' '));
+        }
+        result.push('\n');
+    }
+    result
+}
+
+#[test]
+fn test_builtin_registry_order() {
+    let registry = ScreenRegistry::default();
+    let entries = registry.entries();
+    let titles: Vec<&str> = entries.iter().map(|(title, _)| title.as_str()).collect();
+    assert_eq!(titles, ["About Me", "Skills", "Projects", "Timeline", "Contact"]);
+
+    let modes: Vec<DisplayMode> = entries.iter().map(|(_, mode)| *mode).collect();
+    assert_eq!(modes, [DisplayMode::About, DisplayMode::SkillsVisual, DisplayMode::Projects, DisplayMode::Timeline, DisplayMode::Contact]);
+
+    assert_eq!(registry.position(DisplayMode::Skills), Some(1));
+    assert_eq!(registry.position(DisplayMode::ProjectLinks), Some(2));
+    assert_eq!(registry.position(DisplayMode::Menu), None);
+    assert!(registry.screen_for(DisplayMode::Custom(0)).is_none());
+}
+
+#[test]
+fn test_custom_screen_registration() {
+    let mut registry = ScreenRegistry::new();
+    assert!(registry.is_empty());
+    assert_eq!(registry.register(TalksScreen), DisplayMode::Custom(0));
+    assert_eq!(registry.position(DisplayMode::Custom(0)), Some(0));
+    assert_eq!(registry.screen_for(DisplayMode::Custom(0)).unwrap().title(), "Talks");
+    assert_eq!(registry.len(), 1);
+}
+
+#[test]
+fn test_custom_screen_in_menu_palette_and_navigation() {
+    let mut app = App::new();
+    let mode = app.register_screen(TalksScreen);
+    assert_eq!(mode, DisplayMode::Custom(5));
+    assert!(app.palette_entries.iter().any(|entry| entry.label == "Go to Talks" && entry.action == PaletteAction::Screen(mode)));
+
+    app.display_mode = DisplayMode::Menu;
+    app.menu_index = 4;
+    press(&mut app, KeyCode::Down);
+    assert_eq!(app.display_mode, mode);
+    assert_eq!(app.menu_index, 5);
+
+    // The last screen stays put
+    press(&mut app, KeyCode::Down);
+    assert_eq!(app.display_mode, mode);
+
+    let screen = render_to_string(&mut app);
+    assert!(screen.contains("Rust in the Terminal, 2024"));
+    assert!(screen.contains("Talks"));
+    assert!(screen.contains("q: Quit | Enter: Talks | Esc: Return to Menu"));
+
+    press(&mut app, KeyCode::Up);
+    assert_eq!(app.display_mode, DisplayMode::Contact);
+
+    app.run_palette_action(PaletteAction::Screen(mode));
+    assert_eq!(app.display_mode, mode);
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.display_mode, DisplayMode::Menu);
+    assert_eq!(app.menu_index, 5);
+}
+
+#[test]
+fn test_timeline_down_stops_at_last_screen() {
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Timeline;
+    app.menu_index = 3;
+
+    press(&mut app, KeyCode::Down);
+    assert_eq!(app.display_mode, DisplayMode::Contact);
+    assert_eq!(app.menu_index, 4);
+
+    let mut screens = ScreenRegistry::new();
+    screens.register(AboutScreen);
+    screens.register(TimelineScreen);
+    app.screens = screens;
+    app.display_mode = DisplayMode::Timeline;
+    app.menu_index = 1;
+    press(&mut app, KeyCode::Down);
+    assert_eq!(app.display_mode, DisplayMode::Timeline);
+    assert_eq!(app.menu_index, 1);
+}
+
+#[test]
+fn test_help_lists_the_actions_of_custom_screens() {
+    let mut app = App::new();
+    app.display_mode = app.register_screen(TalksScreen);
+
+    let entries: Vec<(String, &str)> = app.help_sections().into_iter().flat_map(|(_, entries)| entries).collect();
+    assert!(entries.contains(&(String::from("Enter"), "Play the selected talk")));
+    assert!(entries.contains(&(String::from("/"), "Search every section")));
+    assert!(!entries.iter().any(|(_, description)| *description == "Next section"));
+}
+
+#[test]
+fn test_timeline_footer_follows_the_detail_view() {
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Timeline;
+
+    let footer = app.keymap.footer(app.key_scope(), &TimelineScreen.footer_hints(&app));
+    assert!(footer.contains("Enter: Select"));
+    assert!(footer.contains("Esc: Return to Menu"));
+
+    app.timeline_detail_view = true;
+    let footer = app.keymap.footer(app.key_scope(), &TimelineScreen.footer_hints(&app));
+    assert!(footer.contains("Esc: Close details"));
+    assert!(!footer.contains("Select"));
+    assert!(!footer.contains("Filter"));
+}
+
+#[test]
+fn test_screens_decide_scope_and_scrolling() {
+    let mut app = App::new();
+    let talks = app.register_screen(TalksScreen);
+
+    app.display_mode = DisplayMode::ProjectLinks;
+    assert_eq!(app.key_scope(), Scope::ProjectLinks);
+    assert_eq!(app.scroll_target(), None);
+
+    app.display_mode = DisplayMode::Projects;
+    assert_eq!(app.key_scope(), Scope::Content);
+    assert_eq!(app.scroll_target(), Some(DisplayMode::Projects));
+
+    app.display_mode = DisplayMode::Timeline;
+    app.timeline_detail_view = true;
+    assert_eq!(app.key_scope(), Scope::TimelineDetail);
+
+    app.display_mode = talks;
+    assert_eq!(app.key_scope(), Scope::Content);
+    assert_eq!(app.scroll_target(), None);
+
+    app.display_mode = DisplayMode::Menu;
+    assert_eq!(app.key_scope(), Scope::Menu);
+    assert_eq!(app.scroll_target(), Some(DisplayMode::About));
+}
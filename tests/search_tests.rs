@@ -0,0 +1,84 @@
+mod common;
+
+use hire_david_parker::tui::models::DisplayMode;
+use hire_david_parker::tui::search::{highlight_matches, SearchIndex, SearchLocation};
+use hire_david_parker::tui::state::App;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+
+#[test]
+fn test_index_covers_sections_and_data() {
+    let app = App::new();
+    let index = &app.search_index;
+
+    for mode in [DisplayMode::About, DisplayMode::Projects, DisplayMode::Timeline, DisplayMode::Contact] {
+        assert!(index.entries.iter().any(|entry| entry.location.display_mode() == mode), "{:?}", mode);
+    }
+    assert!(index.entries.iter().any(|entry| matches!(entry.location, SearchLocation::TimelineEvent(_))));
+    assert!(index.entries.iter().any(|entry| matches!(entry.location, SearchLocation::SkillCategory(_))));
+    assert!(index.entries.iter().any(|entry| matches!(entry.location, SearchLocation::ProjectLink(_))));
+}
+
+#[test]
+fn test_search_ignores_case_and_groups_by_section() {
+    let app = App::new();
+    let index = &app.search_index;
+
+    assert!(index.search("").is_empty());
+    assert!(index.search("no such text anywhere").is_empty());
+
+    let matches = index.search("warp");
+    assert_eq!(matches, index.search("WARP"));
+    assert!(!matches.is_empty());
+
+    // Each section appears in one contiguous run
+    let sections: Vec<&str> = matches.iter().map(|&i| index.entries[i].location.section()).collect();
+    let mut runs = sections.clone();
+    runs.dedup();
+    let mut unique = runs.clone();
+    unique.sort_unstable();
+    unique.dedup();
+    assert_eq!(runs.len(), unique.len());
+}
+
+#[test]
+fn test_search_finds_json_data() {
+    let app = App::new();
+    let index = &app.search_index;
+
+    let event = &app.timeline_events[0];
+    let matches = index.search(&event.title);
+    assert!(matches.iter().any(|&i| index.entries[i].location == SearchLocation::TimelineEvent(0)));
+
+    let link = &app.project_links[0];
+    let matches = index.search(&link.url);
+    assert!(matches.iter().any(|&i| index.entries[i].location == SearchLocation::ProjectLink(0)));
+}
+
+#[test]
+fn test_default_index_is_empty() {
+    assert!(SearchIndex::default().search("rust").is_empty());
+}
+
+#[test]
+fn test_highlight_matches_splits_spans() {
+    let style = Style::default().bg(Color::Yellow);
+    let mut line = Line::from(vec![Span::raw("Rust and rust"), Span::raw(" and Go")]);
+
+    highlight_matches(&mut line, "RUST", style);
+
+    let contents: Vec<&str> = line.spans.iter().map(|span| span.content.as_ref()).collect();
+    assert_eq!(contents, vec!["Rust", " and ", "rust", " and Go"]);
+    assert_eq!(line.spans[0].style.bg, Some(Color::Yellow));
+    assert_eq!(line.spans[1].style.bg, None);
+    assert_eq!(line.spans[2].style.bg, Some(Color::Yellow));
+}
+
+#[test]
+fn test_highlight_matches_handles_multibyte_text() {
+    let mut line = Line::from("→ café Rust");
+    highlight_matches(&mut line, "rust", Style::default().bg(Color::Yellow));
+
+    let contents: Vec<&str> = line.spans.iter().map(|span| span.content.as_ref()).collect();
+    assert_eq!(contents, vec!["→ café ", "Rust"]);
+}
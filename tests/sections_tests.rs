@@ -0,0 +1,155 @@
+mod common;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use hire_david_parker::content::{ContentError, ContentSource};
+use hire_david_parker::sections::{parse_manifest, section_items_markdown, BuiltinSection};
+use hire_david_parker::tui::models::DisplayMode;
+use hire_david_parker::tui::palette::PaletteAction;
+use hire_david_parker::tui::state::App;
+use hire_david_parker::tui::ui;
+use hire_david_parker::{set_content_source, Section, SectionItem};
+use ratatui::{backend::TestBackend, Terminal};
+use std::fs;
+use std::path::PathBuf;
+
+// Helper function to create an empty, uniquely named content directory
+fn create_content_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("hdp-sections-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn render_to_string(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+    terminal.draw(|f| ui::render(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let mut result = String::new();
+    for row in 0..buffer.area.height {
+        for col in 0..buffer.area.width {
+            result.push(buffer[(col, row)].symbol().chars().next().unwrap_or(' '));
+        }
+        result.push('\n');
+    }
+    result
+}
+
+#[test]
+fn test_toml_and_json_manifests_declare_the_same_sections() {
+    let toml = r#"
+[[sections]]
+builtin = "about"
+
+[[sections]]
+title = "Open Source"
+markdown = "open-source.md"
+data = "open-source.json"
+
+[[sections]]
+builtin = "contact"
+"#;
+    let json = r#"{ "sections": [
+        { "builtin": "about" },
+        { "title": "Open Source", "markdown": "open-source.md", "data": "open-source.json" },
+        { "builtin": "contact" }
+    ] }"#;
+
+    let expected = vec![
+        Section::Builtin(BuiltinSection::About),
+        Section::Custom { title: "Open Source".to_string(), markdown: "open-source.md".to_string(), data: Some("open-source.json".to_string()) },
+        Section::Builtin(BuiltinSection::Contact),
+    ];
+    assert_eq!(parse_manifest("sections.toml", toml).unwrap(), expected);
+    assert_eq!(parse_manifest("manifest.json", json).unwrap(), expected);
+}
+
+#[test]
+fn test_invalid_manifests() {
+    let error = parse_manifest("sections.toml", "[[sections]]\nbuiltin = \"about\"\n\n[[sections]\n").unwrap_err();
+    assert!(matches!(error, ContentError::Parse { line: 4, .. }), "{:?}", error);
+    assert!(error.to_string().starts_with("invalid TOML in 'sections.toml'"));
+
+    for (source, expected) in [
+        (r#"{ "sections": [] }"#, "no sections"),
+        (r#"{ "sections": [{ "title": "Talks" }] }"#, "section 1 needs a markdown file"),
+        (r#"{ "sections": [{ "markdown": "talks.md" }] }"#, "section 1 has no title"),
+        (r#"{ "sections": [{ "builtin": "about", "title": "Me" }] }"#, "cannot set a title"),
+        (r#"{ "sections": [{ "builtin": "about" }, { "builtin": "about" }] }"#, "section 2 repeats"),
+    ] {
+        let error = parse_manifest("manifest.json", source).unwrap_err();
+        assert!(matches!(error, ContentError::Schema { ref message, .. } if message.contains(expected)), "{}", source);
+    }
+
+    assert!(matches!(parse_manifest("manifest.json", r#"{ "sections": [{ "builtin": "blog" }] }"#), Err(ContentError::Schema { .. })));
+}
+
+#[test]
+fn test_section_items_render_as_markdown() {
+    let items = vec![
+        SectionItem {
+            title: "Rust in the Terminal".to_string(),
+            subtitle: Some("RustConf".to_string()),
+            date: Some("2024".to_string()),
+            description: Some("Building TUIs with ratatui.".to_string()),
+            url: Some("https://example.com/talk".to_string()),
+            tags: vec!["Rust".to_string(), "TUI".to_string()],
+        },
+        SectionItem { title: "Meetup".to_string(), subtitle: None, date: None, description: None, url: None, tags: Vec::new() },
+    ];
+
+    assert_eq!(
+        section_items_markdown(&items),
+        "### Rust in the Terminal\n\n*RustConf · 2024*\n\nBuilding TUIs with ratatui.\n\n\
+         [https://example.com/talk](https://example.com/talk)\n\n**Tags:** Rust, TUI\n\n### Meetup\n\n"
+    );
+}
+
+#[test]
+fn test_manifest_builds_the_sidebar_and_custom_sections() {
+    let dir = create_content_dir("manifest");
+    fs::write(
+        dir.join("sections.toml"),
+        "[[sections]]\nbuiltin = \"about\"\n\n[[sections]]\ntitle = \"Speaking\"\nmarkdown = \"speaking.md\"\ndata = \"talks.json\"\n\n\
+         [[sections]]\ntitle = \"Volunteering\"\nmarkdown = \"volunteering.md\"\n\n[[sections]]\nbuiltin = \"contact\"\n",
+    )
+    .unwrap();
+    fs::write(dir.join("speaking.md"), "# Speaking\n\nTalks I have given.\n").unwrap();
+    fs::write(dir.join("talks.json"), r#"[{ "title": "Rust in the Terminal", "date": "2024", "url": "https://example.com/talk" }]"#).unwrap();
+
+    set_content_source(ContentSource::Directory(dir.clone()));
+    let mut app = App::new();
+    set_content_source(ContentSource::Embedded);
+    fs::remove_dir_all(dir).unwrap();
+
+    let titles: Vec<String> = app.screens.entries().into_iter().map(|(title, _)| title).collect();
+    assert_eq!(titles, ["About Me", "Speaking", "Volunteering", "Contact"]);
+    assert!(app.palette_entries.iter().any(|entry| entry.label == "Go to Speaking" && entry.action == PaletteAction::Screen(DisplayMode::Custom(1))));
+
+    // The missing volunteering.md is reported on its own screen
+    assert!(matches!(app.content_error(&["volunteering.md"]), Some(ContentError::Missing { .. })));
+
+    app.display_mode = DisplayMode::Menu;
+    app.skip_auto_switch = true;
+    app.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+    app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    assert_eq!(app.display_mode, DisplayMode::Custom(1));
+    assert_eq!(app.scroll_target(), Some(DisplayMode::Custom(1)));
+    assert!(app.markdown_content(DisplayMode::Custom(1)).unwrap().contains("### Rust in the Terminal"));
+    assert_eq!(app.section_links(DisplayMode::Custom(1)).len(), 1);
+
+    let screen = render_to_string(&mut app);
+    assert!(screen.contains("Talks I have given."));
+    assert!(screen.contains("Rust in the Terminal"));
+    assert!(screen.contains("Esc: Return to Menu"));
+
+    app.search.query = "talks i have given".to_string();
+    app.update_search();
+    let location = app.search_index.entries[app.search.matches[0]].location;
+    assert_eq!(location.display_mode(), DisplayMode::Custom(1));
+
+    app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+    app.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+    app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    assert_eq!(app.display_mode, DisplayMode::Custom(2));
+    assert!(render_to_string(&mut app).contains("'volunteering.md' was not found"));
+}
@@ -1,7 +1,7 @@
 mod common;
 
 use hire_david_parker::tui::state::App;
-use hire_david_parker::tui::models::{DisplayMode, TimelineFilter};
+use hire_david_parker::tui::models::{DisplayMode, TimelineFilter, TimelineType};
 
 #[test]
 fn test_app_initialization() {
@@ -16,14 +16,15 @@ fn test_app_initialization() {
     assert_eq!(app.previous_mode, DisplayMode::Menu);
     assert_eq!(app.timeline_filter, TimelineFilter::All);
     assert_eq!(app.timeline_event_index, 0);
-    assert_eq!(app.timeline_detail_view, false);
-    assert_eq!(app.should_exit, false);
+    assert!(!app.timeline_detail_view);
+    assert!(!app.should_exit);
+    assert!(app.content_errors.is_empty());
     
     // Test that content is loaded
     assert!(!app.about_content.is_empty());
     assert!(!app.skills_content.is_empty());
     assert!(!app.projects_content.is_empty());
-    assert!(!app.why_warp_content.is_empty());
+    assert!(!app.contact_content.is_empty());
     assert!(!app.welcome_content.is_empty());
     assert!(!app.timeline_content.is_empty());
     
@@ -35,6 +36,10 @@ fn test_app_initialization() {
     let mut sorted_years = years.clone();
     sorted_years.sort();
     assert_eq!(years, sorted_years);
+    
+    // Test that event types come from the data rather than the year
+    assert!(app.timeline_events.iter().any(|e| e.event_type == TimelineType::Career));
+    assert!(app.timeline_events.iter().any(|e| e.event_type == TimelineType::Education));
 }
 
 #[test]
@@ -46,4 +51,60 @@ fn test_filtered_events() {
     
     // Test that filtered events returns all events for now
     assert_eq!(filtered_events.len(), app.timeline_events.len());
-}
\ No newline at end of file
+}
+#[test]
+fn test_timeline_filters() {
+    let mut app = App::new();
+    
+    // Filtering by type only keeps events of that type
+    app.timeline_filter = TimelineFilter::Type(TimelineType::Career);
+    let career_events = app.get_filtered_events();
+    assert!(!career_events.is_empty());
+    assert!(career_events.iter().all(|e| e.event_type == TimelineType::Career));
+    
+    // Filtering by technology is case insensitive
+    app.timeline_filter = TimelineFilter::Technology("php".to_string());
+    let php_events = app.get_filtered_events();
+    assert!(!php_events.is_empty());
+    assert!(php_events.len() < app.timeline_events.len());
+    
+    // Filtering by year range is inclusive
+    app.timeline_filter = TimelineFilter::YearRange(2020, 2029);
+    let recent_events = app.get_filtered_events();
+    assert!(!recent_events.is_empty());
+    assert!(recent_events.iter().all(|e| (2020..=2029).contains(&e.year)));
+}
+
+#[test]
+fn test_available_timeline_filters() {
+    let app = App::new();
+    let filters = app.available_timeline_filters();
+    
+    // All comes first and every filter matches at least one event
+    assert_eq!(filters[0], TimelineFilter::All);
+    assert!(filters.contains(&TimelineFilter::Type(TimelineType::Education)));
+    assert!(filters.contains(&TimelineFilter::YearRange(2000, 2009)));
+    assert!(filters.iter().any(|f| matches!(f, TimelineFilter::Technology(_))));
+    assert!(!filters.contains(&TimelineFilter::Type(TimelineType::Certification)));
+    
+    for filter in &filters {
+        assert!(app.timeline_events.iter().any(|e| filter.matches(e)), "{:?} matches nothing", filter);
+    }
+}
+
+#[test]
+fn test_cycle_timeline_filter() {
+    let mut app = App::new();
+    let filters = app.available_timeline_filters();
+    app.timeline_index = 3;
+    
+    // Cycling forward moves to the next filter and resets the selection
+    app.cycle_timeline_filter(true);
+    assert_eq!(app.timeline_filter, filters[1]);
+    assert_eq!(app.timeline_index, 0);
+    
+    // Cycling backward from the first filter wraps around
+    app.cycle_timeline_filter(false);
+    app.cycle_timeline_filter(false);
+    assert_eq!(app.timeline_filter, filters[filters.len() - 1]);
+}
@@ -0,0 +1,114 @@
+mod common;
+
+use hire_david_parker::text::{markdown_to_text, skill_bar, skills_to_text, text_to_string, timeline_to_text, wrap_text};
+use hire_david_parker::tui::models::{Skill, SkillCategory, SkillsData};
+use hire_david_parker::tui::theme::{ColorDepth, Theme};
+use hire_david_parker::{parse_contact_fields, TimelineDate, TimelineEvent, TimelineType, YearMonth};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Span, Text};
+
+#[test]
+fn test_skill_bar() {
+    assert_eq!(skill_bar(50), "[█████░░░░░] 50%");
+    assert_eq!(skill_bar(0), "[░░░░░░░░░░] 0%");
+    assert_eq!(skill_bar(100), "[██████████] 100%");
+}
+
+#[test]
+fn test_markdown_to_text_plain_and_styled() {
+    let plain = markdown_to_text("# Title\n\nSome **bold** text", ColorDepth::Monochrome);
+    assert!(plain.contains("Title"));
+    assert!(plain.contains("Some bold text"));
+    assert!(!plain.contains('\x1b'));
+
+    let styled = markdown_to_text("# Title\n\nSome **bold** text", ColorDepth::TrueColor);
+    assert!(styled.contains("\x1b["));
+}
+
+#[test]
+fn test_text_colors_follow_the_color_depth() {
+    let text = Text::from(Span::styled("red", Style::default().fg(Color::Rgb(250, 10, 10))));
+
+    assert_eq!(text_to_string(&text, ColorDepth::TrueColor), "\x1b[38;2;250;10;10mred\x1b[0m");
+    assert_eq!(text_to_string(&text, ColorDepth::Ansi256), "\x1b[38;5;196mred\x1b[0m");
+    assert_eq!(text_to_string(&text, ColorDepth::Ansi16), "\x1b[91mred\x1b[0m");
+    assert_eq!(text_to_string(&text, ColorDepth::Monochrome), "red");
+}
+
+#[test]
+fn test_skills_to_text_aligns_bars() {
+    let skills = SkillsData {
+        categories: vec![SkillCategory {
+            name: String::from("Languages"),
+            skills: vec![
+                Skill { name: String::from("Rust"), level: 90 },
+                Skill { name: String::from("TypeScript"), level: 40 },
+            ],
+        }],
+    };
+
+    let text = skills_to_text(&skills, ColorDepth::Monochrome);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], "Languages");
+    assert_eq!(lines[1].find('['), lines[2].find('['));
+    assert!(lines[1].ends_with("90%"));
+}
+
+#[test]
+fn test_timeline_to_text() {
+    let event = TimelineEvent {
+        year: 2018,
+        event_type: TimelineType::Career,
+        title: String::from("Engineer"),
+        organization: String::from("Acme"),
+        description: String::from("Built things"),
+        highlights: Some(vec![String::from("Shipped v1")]),
+        technologies: Some(vec![String::from("Rust")]),
+        start: Some(TimelineDate::Month(YearMonth { year: 2018, month: 3 })),
+        end: Some(TimelineDate::Month(YearMonth { year: 2020, month: 5 })),
+    };
+
+    let text = timeline_to_text(&[&event], ColorDepth::Monochrome);
+    assert!(text.starts_with("2018  Engineer | Acme  [Career]"));
+    assert!(text.contains("Mar 2018 - May 2020 (2 yrs 3 mos)"));
+    assert!(text.contains("• Shipped v1"));
+    assert!(text.contains("Technologies: Rust"));
+
+    // Styled output takes its colors from the default theme
+    let theme = Theme::default();
+    assert_eq!((theme.highlight, theme.gauge, theme.timeline_career), (Color::Yellow, Color::Green, Color::Cyan));
+    let styled = timeline_to_text(&[&event], ColorDepth::Ansi16);
+    assert!(styled.contains("\x1b[1;33mEngineer\x1b[0m"));
+    assert!(styled.contains("\x1b[36m[Career]\x1b[0m"));
+    assert!(styled.contains("\x1b[32mTechnologies: Rust\x1b[0m"));
+}
+
+#[test]
+fn test_parse_contact_fields() {
+    let fields = parse_contact_fields("- **Email:** me@example.com\n- **GitHub:** [github.com/me](https://github.com/me)\n\nOther text");
+
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[0].label, "Email");
+    assert_eq!(fields[0].value, "me@example.com");
+    assert_eq!(fields[0].url, None);
+    assert!(fields[1].is_named("github"));
+    assert_eq!(fields[1].url.as_deref(), Some("https://github.com/me"));
+}
+
+#[test]
+fn test_wrap_text() {
+    let wrapped = wrap_text("one two three four five six", 10);
+    assert_eq!(wrapped, "one two\nthree four\nfive six");
+
+    let wrapped = wrap_text("    • alpha beta gamma delta", 17);
+    assert_eq!(wrapped, "    • alpha beta\n      gamma delta");
+
+    let wrapped = wrap_text("-   **Email:** someone@example.com", 20);
+    assert_eq!(wrapped, "-   **Email:**\n    someone@example.com");
+
+    let wrapped = wrap_text("## A heading that is long\n```\ncode that is long\n```", 8);
+    assert_eq!(wrapped, "## A heading that is long\n```\ncode that is long\n```");
+
+    let wrapped = wrap_text("日本語 日本語 日本語", 14);
+    assert_eq!(wrapped, "日本語 日本語\n日本語");
+}
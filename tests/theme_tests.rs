@@ -0,0 +1,147 @@
+mod common;
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use hire_david_parker::tui::theme::{load_themes, select_theme, ColorDepth, Theme, ThemeError};
+use hire_david_parker::TimelineType;
+use ratatui::style::{Color, Modifier};
+
+// Helper function to create an empty, uniquely named theme directory
+fn create_theme_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("hdp-themes-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_builtin_themes() {
+    let names: Vec<String> = Theme::builtin().into_iter().map(|theme| theme.name).collect();
+    assert_eq!(names, ["dark", "light", "solarized", "high-contrast"]);
+    assert_eq!(Theme::default(), Theme::dark());
+}
+
+#[test]
+fn test_toml_theme_overrides_its_base() {
+    let theme = Theme::from_toml("mine", "base = \"light\"\nborder = \"#112233\"\nlink = \"light cyan\"\nmuted = \"8\"\n").unwrap();
+    assert_eq!(theme.name, "mine");
+    assert_eq!(theme.border, Color::Rgb(0x11, 0x22, 0x33));
+    assert_eq!(theme.link, Color::LightCyan);
+    assert_eq!(theme.muted, Color::Indexed(8));
+    assert_eq!(theme.highlight, Theme::light().highlight);
+
+    let theme = Theme::from_toml("file-name", "name = \"Nord\"").unwrap();
+    assert_eq!(theme.name, "Nord");
+    assert_eq!(theme.title, Theme::dark().title);
+}
+
+#[test]
+fn test_timeline_colors_come_from_the_theme() {
+    for theme in Theme::builtin() {
+        let mut colors: Vec<Color> = TimelineType::ALL.iter().map(|&event_type| theme.timeline_color(event_type)).collect();
+        colors.dedup();
+        assert_eq!(colors.len(), TimelineType::ALL.len(), "{}", theme.name);
+    }
+    assert_ne!(Theme::light().timeline_color(TimelineType::Other), Color::White);
+
+    let theme = Theme::from_toml("mine", "timeline_career = \"#112233\"\ntimeline_other = \"gray\"\n").unwrap();
+    assert_eq!(theme.timeline_color(TimelineType::Career), Color::Rgb(0x11, 0x22, 0x33));
+    assert_eq!(theme.timeline_color(TimelineType::Other), Color::Gray);
+    assert_eq!(theme.timeline_color(TimelineType::Project), Theme::dark().timeline_project);
+}
+
+#[test]
+fn test_invalid_toml_themes() {
+    let error = Theme::from_toml("mine", "border = \"not-a-color\"").unwrap_err();
+    assert!(matches!(error, ThemeError::Parse { .. }));
+    assert!(error.to_string().contains("unknown color 'not-a-color' for border"));
+
+    let error = Theme::from_toml("mine", "boarder = \"blue\"").unwrap_err();
+    assert!(error.to_string().contains("boarder"));
+
+    let error = Theme::from_toml("mine", "base = \"neon\"").unwrap_err();
+    assert!(error.to_string().contains("unknown base theme 'neon'"));
+}
+
+#[test]
+fn test_load_themes_from_directory() {
+    let dir = create_theme_dir("load");
+    fs::write(dir.join("nord.toml"), "border = \"#5e81ac\"").unwrap();
+    fs::write(dir.join("dark.toml"), "border = \"magenta\"").unwrap();
+    fs::write(dir.join("broken.toml"), "border = ").unwrap();
+    fs::write(dir.join("notes.txt"), "not a theme").unwrap();
+
+    let (themes, errors) = load_themes(Some(&dir));
+    assert_eq!(themes.len(), 5);
+    assert_eq!(themes[0].border, Color::Magenta);
+    assert_eq!(themes[4].name, "nord");
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].to_string().contains("broken"));
+
+    let (themes, errors) = load_themes(Some(&dir.join("missing")));
+    assert_eq!(themes, Theme::builtin());
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn test_select_theme_by_name_or_file() {
+    let mut themes = Theme::builtin();
+    assert_eq!(select_theme(&mut themes, "Solarized").unwrap(), 2);
+
+    let error = select_theme(&mut themes, "neon").unwrap_err();
+    assert!(error.to_string().contains("expected one of: dark, light, solarized, high-contrast"));
+
+    let dir = create_theme_dir("select");
+    let path = dir.join("paper.toml");
+    fs::write(&path, "base = \"light\"\ntitle = \"black\"").unwrap();
+    assert_eq!(select_theme(&mut themes, path.to_str().unwrap()).unwrap(), 4);
+    assert_eq!(themes[4].title, Color::Black);
+
+    let error = select_theme(&mut themes, dir.join("missing.toml").to_str().unwrap()).unwrap_err();
+    assert!(matches!(error, ThemeError::Io { .. }));
+}
+
+fn color_depth(vars: &[(&str, &str)]) -> ColorDepth {
+    let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    ColorDepth::from_env(|name| vars.get(name).cloned())
+}
+
+#[test]
+fn test_color_depth_detection() {
+    assert_eq!(color_depth(&[("NO_COLOR", "1"), ("COLORTERM", "truecolor")]), ColorDepth::Monochrome);
+    assert_eq!(color_depth(&[("TERM", "dumb")]), ColorDepth::Monochrome);
+    assert_eq!(color_depth(&[("NO_COLOR", ""), ("TERM", "xterm-256color")]), ColorDepth::Ansi256);
+    assert_eq!(color_depth(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")]), ColorDepth::TrueColor);
+    assert_eq!(color_depth(&[("TERM", "xterm-direct")]), ColorDepth::TrueColor);
+    assert_eq!(color_depth(&[("TERM", "xterm")]), ColorDepth::Ansi16);
+}
+
+#[test]
+fn test_colors_are_downgraded_to_the_color_depth() {
+    assert_eq!(ColorDepth::TrueColor.adapt(Color::Rgb(1, 2, 3)), Color::Rgb(1, 2, 3));
+    assert_eq!(ColorDepth::Ansi256.adapt(Color::Rgb(255, 0, 0)), Color::Indexed(196));
+    assert_eq!(ColorDepth::Ansi256.adapt(Color::Rgb(128, 128, 128)), Color::Indexed(244));
+    assert_eq!(ColorDepth::Ansi256.adapt(Color::Cyan), Color::Cyan);
+    assert_eq!(ColorDepth::Ansi16.adapt(Color::Rgb(250, 10, 10)), Color::LightRed);
+    assert_eq!(ColorDepth::Ansi16.adapt(Color::Indexed(196)), Color::LightRed);
+    assert_eq!(ColorDepth::Ansi16.adapt(Color::Indexed(4)), Color::Blue);
+    assert_eq!(ColorDepth::Ansi16.adapt(Color::Magenta), Color::Magenta);
+    assert_eq!(ColorDepth::Monochrome.adapt(Color::Yellow), Color::Reset);
+    assert_eq!(ColorDepth::Ansi16.adapt(Color::Reset), Color::Reset);
+}
+
+#[test]
+fn test_monochrome_styles_use_modifiers() {
+    let mut theme = Theme::from_toml("plain", "monochrome = true").unwrap();
+    assert!(theme.monochrome);
+    assert!(theme.selected().add_modifier.contains(Modifier::REVERSED));
+    assert!(theme.focused().add_modifier.contains(Modifier::REVERSED));
+    assert!(theme.focused().bg.is_none());
+    assert!(theme.link_style().add_modifier.contains(Modifier::UNDERLINED));
+
+    theme.monochrome = false;
+    assert!(!theme.selected().add_modifier.contains(Modifier::REVERSED));
+    assert_eq!(theme.focused().bg, Some(theme.highlight));
+}
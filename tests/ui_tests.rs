@@ -1,13 +1,15 @@
 mod common;
 
 use hire_david_parker::tui::state::App;
-use hire_david_parker::tui::models::{DisplayMode, TimelineEvent, TimelineType, SkillCategory, Skill, Link};
+use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use hire_david_parker::tui::models::{ClickTarget, DisplayMode, TimelineDate, TimelineEvent, TimelineFilter, TimelineType, SkillCategory, Skill, YearMonth};
 use ratatui::{
     backend::TestBackend,
     buffer::Buffer,
     Terminal,
 };
 use hire_david_parker::tui::ui;
+use hire_david_parker::ContentError;
 
 #[test]
 fn test_ui_menu_sidebar_rendering() {
@@ -104,6 +106,8 @@ fn test_ui_timeline_rendering() {
             description: "Test Description".to_string(),
             highlights: Some(vec!["Highlight 1".to_string()]),
             technologies: Some(vec!["Tech 1".to_string()]),
+            start: None,
+            end: None,
         });
     }
     
@@ -156,6 +160,30 @@ fn test_ui_skills_visual_rendering() {
     assert!(buffer_content.contains("Skills:"));
 }
 
+#[test]
+fn test_ui_skills_page_hint_follows_the_rendered_page_size() {
+    let mut app = App::new();
+    app.display_mode = DisplayMode::SkillsVisual;
+    app.skills_data.categories = vec![SkillCategory {
+        name: "Languages".to_string(),
+        skills: (0..4).map(|i| Skill { name: format!("Skill {}", i), level: 50 }).collect(),
+    }];
+
+    // Four skills fit on a tall terminal but not a short one
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let content = buffer_to_string(terminal.backend().buffer());
+    assert!(!content.contains("Pages"));
+    assert!(!content.contains("Page 1/"));
+
+    let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let content = buffer_to_string(terminal.backend().buffer());
+    assert!(app.skills_per_page < 4);
+    assert!(content.contains("Page 1/"));
+    assert!(content.contains("←/→: Pages"));
+}
+
 #[test]
 fn test_ui_welcome_rendering() {
     // Setup test terminal
@@ -176,9 +204,9 @@ fn test_ui_welcome_rendering() {
     // Get the buffer to check contents
     let buffer = terminal.backend().buffer().clone();
     
-    // Check that the welcome content is rendered
+    // Check that the about content is shown alongside the menu
     let buffer_content = buffer_to_string(&buffer);
-    assert!(buffer_content.contains("Instructions"));
+    assert!(buffer_content.contains("About Me"));
 }
 
 #[test]
@@ -230,29 +258,6 @@ fn test_ui_project_links_rendering() {
     assert!(buffer_content.contains("Project Links"));
 }
 
-#[test]
-fn test_ui_why_warp_rendering() {
-    // Setup test terminal
-    let backend = TestBackend::new(80, 30);
-    let mut terminal = Terminal::new(backend).unwrap();
-    let mut app = App::new();
-    
-    // Set why warp mode
-    app.display_mode = DisplayMode::WhyWarp;
-    
-    // Basic test to ensure UI rendering doesn't panic
-    terminal.draw(|f| {
-        ui::render(f, &mut app);
-    }).unwrap();
-    
-    // Get the buffer to check contents
-    let buffer = terminal.backend().buffer().clone();
-    
-    // Check that the why warp content is rendered
-    let buffer_content = buffer_to_string(&buffer);
-    assert!(buffer_content.contains("Why Warp?"));
-}
-
 #[test]
 fn test_ui_contact_rendering() {
     // Setup test terminal
@@ -296,6 +301,8 @@ fn test_ui_timeline_details_rendering() {
             description: "Test Description 1".to_string(),
             highlights: Some(vec!["Highlight 1".to_string(), "Highlight 2".to_string()]),
             technologies: Some(vec!["Tech 1".to_string(), "Tech 2".to_string()]),
+            start: None,
+            end: None,
         });
         
         app.timeline_events.push(TimelineEvent {
@@ -306,6 +313,8 @@ fn test_ui_timeline_details_rendering() {
             description: "Test Description 2".to_string(),
             highlights: Some(vec!["Highlight A".to_string(), "Highlight B".to_string()]),
             technologies: Some(vec!["Tech A".to_string(), "Tech B".to_string()]),
+            start: None,
+            end: None,
         });
     }
     
@@ -329,6 +338,55 @@ fn test_ui_timeline_details_rendering() {
     assert!(buffer_content.contains("Description"));
     assert!(buffer_content.contains("Highlights"));
     assert!(buffer_content.contains("Technologies"));
+    assert!(buffer_content.contains(event.event_type.label()));
+}
+
+#[test]
+fn test_ui_timeline_type_markers() {
+    // Setup test terminal
+    let backend = TestBackend::new(100, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    
+    // Use one event per type so every marker is drawn
+    app.display_mode = DisplayMode::Timeline;
+    app.timeline_events = vec![
+        TimelineEvent {
+            year: 2010,
+            event_type: TimelineType::Education,
+            title: "Degree".to_string(),
+            organization: "University".to_string(),
+            description: "Studied".to_string(),
+            highlights: None,
+            technologies: None,
+            start: None,
+            end: None,
+        },
+        TimelineEvent {
+            year: 2020,
+            event_type: TimelineType::Career,
+            title: "Engineer".to_string(),
+            organization: "Company".to_string(),
+            description: "Worked".to_string(),
+            highlights: None,
+            technologies: None,
+            start: None,
+            end: None,
+        },
+    ];
+    app.timeline_index = 0;
+    
+    terminal.draw(|f| {
+        ui::render(f, &mut app);
+    }).unwrap();
+    
+    // Check that each type has its own marker and a legend entry
+    let buffer_content = buffer_to_string(&terminal.backend().buffer().clone());
+    assert!(buffer_content.contains(TimelineType::Education.symbol()));
+    assert!(buffer_content.contains(TimelineType::Career.symbol()));
+    assert!(buffer_content.contains("Career"));
+    assert!(buffer_content.contains("Education"));
+    assert!(!buffer_content.contains(TimelineType::Certification.label()));
 }
 
 #[test]
@@ -358,7 +416,514 @@ fn test_ui_empty_timeline_rendering() {
     assert!(buffer_content.contains("No timeline events found"));
 }
 
+#[test]
+fn test_ui_content_error_rendering() {
+    // Setup test terminal
+    let backend = TestBackend::new(80, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    
+    // Simulate a contact file that failed to load
+    app.display_mode = DisplayMode::Contact;
+    app.content_errors.push(ContentError::Missing { file: "contact.md".to_string() });
+    
+    terminal.draw(|f| {
+        ui::render(f, &mut app);
+    }).unwrap();
+    
+    // Check that the error panel replaces the section content
+    let buffer_content = buffer_to_string(&terminal.backend().buffer().clone());
+    assert!(buffer_content.contains("Content Error"));
+    assert!(buffer_content.contains("contact.md"));
+    assert!(!buffer_content.contains("Contact Information"));
+    
+    // Other sections still render normally
+    app.display_mode = DisplayMode::About;
+    terminal.draw(|f| {
+        ui::render(f, &mut app);
+    }).unwrap();
+    
+    let buffer_content = buffer_to_string(&terminal.backend().buffer().clone());
+    assert!(!buffer_content.contains("Content Error"));
+}
+
+#[test]
+fn test_ui_timeline_filter_rendering() {
+    // Setup test terminal
+    let backend = TestBackend::new(100, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    
+    // Filter down to education events
+    app.display_mode = DisplayMode::Timeline;
+    app.timeline_filter = TimelineFilter::Type(TimelineType::Education);
+    
+    terminal.draw(|f| {
+        ui::render(f, &mut app);
+    }).unwrap();
+    
+    // Check that the active filter is shown and the details follow the filtered list
+    let buffer_content = buffer_to_string(&terminal.backend().buffer().clone());
+    let first_education = app.get_filtered_events()[0].title.clone();
+    assert!(buffer_content.contains("Filter: Education"));
+    assert!(buffer_content.contains(&first_education));
+    
+    // A filter with no matches shows a message instead of details
+    app.timeline_filter = TimelineFilter::Type(TimelineType::Certification);
+    terminal.draw(|f| {
+        ui::render(f, &mut app);
+    }).unwrap();
+    
+    let buffer_content = buffer_to_string(&terminal.backend().buffer().clone());
+    assert!(buffer_content.contains("No timeline events match"));
+}
+
+#[test]
+fn test_ui_timeline_span_rendering() {
+    // Setup test terminal
+    let backend = TestBackend::new(100, 40);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    
+    // Two overlapping roles, one of them ongoing
+    app.display_mode = DisplayMode::Timeline;
+    app.timeline_events = vec![
+        TimelineEvent {
+            year: 2018,
+            event_type: TimelineType::Career,
+            title: "Engineer".to_string(),
+            organization: "First Co".to_string(),
+            description: "Worked".to_string(),
+            highlights: None,
+            technologies: None,
+            start: Some(TimelineDate::Month(YearMonth { year: 2018, month: 3 })),
+            end: Some(TimelineDate::Month(YearMonth { year: 2020, month: 5 })),
+        },
+        TimelineEvent {
+            year: 2019,
+            event_type: TimelineType::Project,
+            title: "Side Project".to_string(),
+            organization: "Open Source".to_string(),
+            description: "Built".to_string(),
+            highlights: None,
+            technologies: None,
+            start: Some(TimelineDate::Month(YearMonth { year: 2019, month: 1 })),
+            end: Some(TimelineDate::Present),
+        },
+    ];
+    app.timeline_index = 0;
+    
+    terminal.draw(|f| {
+        ui::render(f, &mut app);
+    }).unwrap();
+    
+    // Overlapping spans are drawn as bars on separate rows
+    let buffer_content = buffer_to_string(&terminal.backend().buffer().clone());
+    let bar_rows = buffer_content.lines().filter(|line| line.contains('━')).count();
+    assert_eq!(bar_rows, 2);
+    
+    // The details show the date range and computed duration
+    assert!(buffer_content.contains("Mar 2018 - May 2020 (2 yrs 3 mos)"));
+}
+
 // Helper function to convert buffer to string for checking content
+
+#[test]
+fn test_ui_scrollable_pane_indicators() {
+    // A short terminal cannot show the whole projects section
+    let backend = TestBackend::new(60, 16);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Projects;
+    app.menu_index = 2;
+
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let top = buffer_to_string(terminal.backend().buffer());
+    let scroll = app.scroll_state(DisplayMode::Projects);
+    assert!(scroll.max_offset() > 0);
+    assert!(top.contains("more below"));
+    assert!(!top.contains("more above"));
+
+    app.scroll_state_mut(DisplayMode::Projects).offset = scroll.max_offset();
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let bottom = buffer_to_string(terminal.backend().buffer());
+    assert!(bottom.contains("more above"));
+    assert!(!bottom.contains("more below"));
+    assert_ne!(top, bottom);
+}
+
+#[test]
+fn test_ui_scroll_uses_wrapped_line_count() {
+    let mut app = App::new();
+    app.display_mode = DisplayMode::About;
+    app.menu_index = 0;
+
+    let mut heights = Vec::new();
+    for width in [60, 120] {
+        let mut terminal = Terminal::new(TestBackend::new(width, 30)).unwrap();
+        terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+        heights.push(app.scroll_state(DisplayMode::About).content_height);
+    }
+
+    // Narrower panes wrap into more lines
+    assert!(heights[0] > heights[1]);
+}
+
+
+#[test]
+fn test_ui_search_overlay_groups_results() {
+    let backend = TestBackend::new(100, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    app.search.prompt_open = true;
+    app.search.query = "warp".to_string();
+    app.update_search();
+
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let content = buffer_to_string(terminal.backend().buffer());
+    assert!(content.contains("Search: /warp"));
+    assert!(content.contains("Projects"));
+    assert!(content.contains("matches"));
+    assert!(content.contains("Enter: Jump"));
+}
+
+#[test]
+fn test_ui_search_jump_scrolls_to_match() {
+    // Short terminal so the last project is below the fold
+    let backend = TestBackend::new(60, 16);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    app.search.query = "Warp Commands Navigator".to_string();
+    app.update_search();
+    app.jump_to_selected_match();
+
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let content = buffer_to_string(terminal.backend().buffer());
+    assert_eq!(app.display_mode, DisplayMode::Projects);
+    assert!(app.scroll_state(DisplayMode::Projects).offset > 0);
+    assert!(app.search.pending_scroll.is_none());
+    assert!(content.contains("Warp Commands"));
+    assert!(content.contains("n/N: Match 1/"));
+}
+
+
+#[test]
+fn test_ui_command_palette_overlay() {
+    let backend = TestBackend::new(100, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    app.palette.open = true;
+    app.palette.query = "go to".to_string();
+    app.update_palette();
+
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let content = buffer_to_string(terminal.backend().buffer());
+    assert!(content.contains("Command Palette"));
+    assert!(content.contains("> go to"));
+    assert!(content.contains("› Go to About Me"));
+    assert!(content.contains("Go to Contact"));
+    assert!(content.contains("Enter: Run"));
+}
+
+
+fn click(app: &mut App, column: u16, row: u16) {
+    app.handle_mouse_event(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    });
+}
+
+#[test]
+fn test_ui_click_menu_item_switches_screen() {
+    let backend = TestBackend::new(80, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+
+    // The sidebar starts below the title bar, inside its own border
+    let buffer = buffer_to_string(terminal.backend().buffer());
+    let row = buffer.lines().position(|line| line.contains("Contact")).unwrap() as u16;
+    assert_eq!(app.click_target_at(2, row), Some(ClickTarget::MenuItem(4)));
+
+    click(&mut app, 2, row);
+    assert_eq!(app.display_mode, DisplayMode::Contact);
+    assert_eq!(app.menu_index, 4);
+
+    // Clicking a border or empty space does nothing
+    click(&mut app, 0, 0);
+    assert_eq!(app.display_mode, DisplayMode::Contact);
+}
+
+#[test]
+fn test_ui_click_timeline_point_selects_event() {
+    let backend = TestBackend::new(120, 40);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Timeline;
+    app.menu_index = 3;
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+
+    let last = app.get_filtered_events().len() - 1;
+    let (area, _) = *app.click_targets
+        .iter()
+        .rev()
+        .find(|(_, target)| *target == ClickTarget::TimelineEvent(last))
+        .unwrap();
+
+    click(&mut app, area.x, area.y);
+    assert_eq!(app.timeline_index, last);
+    assert_eq!(app.timeline_event_index, last);
+    assert_eq!(app.display_mode, DisplayMode::Timeline);
+}
+
+#[test]
+fn test_ui_project_links_have_click_targets() {
+    let backend = TestBackend::new(100, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    app.display_mode = DisplayMode::ProjectLinks;
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+
+    let links: Vec<_> = app.click_targets
+        .iter()
+        .filter(|(_, target)| matches!(target, ClickTarget::ProjectLink(_)))
+        .collect();
+    assert_eq!(links.len(), app.project_links.len());
+    assert_eq!(links[1].0.y, links[0].0.y + 1);
+}
+
+#[test]
+fn test_ui_clicks_ignored_under_overlays() {
+    let backend = TestBackend::new(80, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let (area, _) = app.click_targets[4];
+
+    app.palette.open = true;
+    click(&mut app, area.x, area.y);
+    assert_eq!(app.display_mode, DisplayMode::About);
+}
+
+
+#[test]
+fn test_ui_inline_link_targets_match_rendered_text() {
+    // Narrow widths force the contact links to wrap
+    for width in [50, 64, 80, 120] {
+        let backend = TestBackend::new(width, 40);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = App::new();
+        app.display_mode = DisplayMode::Contact;
+        app.menu_index = 4;
+        terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+
+        let links = app.section_links(DisplayMode::Contact);
+        let buffer = terminal.backend().buffer().clone();
+        for (index, link) in links.iter().enumerate() {
+            let rendered: String = app.click_targets
+                .iter()
+                .filter(|(_, target)| *target == ClickTarget::InlineLink(DisplayMode::Contact, index))
+                .flat_map(|(area, _)| (area.x..area.right()).map(move |x| (x, area.y)))
+                .map(|(x, y)| buffer[(x, y)].symbol().to_string())
+                .collect();
+            assert_eq!(rendered, link.text, "width {}", width);
+        }
+    }
+}
+
+#[test]
+fn test_ui_focused_link_is_highlighted_and_revealed() {
+    let backend = TestBackend::new(60, 14);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Contact;
+    app.menu_index = 4;
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+
+    app.cycle_link_focus(false);
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    assert!(app.scroll_state(DisplayMode::Contact).offset > 0);
+    assert!(!app.link_focus.unwrap().reveal);
+
+    let index = app.link_focus.unwrap().index;
+    let (area, _) = *app.click_targets
+        .iter()
+        .find(|(_, target)| *target == ClickTarget::InlineLink(DisplayMode::Contact, index))
+        .unwrap();
+    let cell = &terminal.backend().buffer()[(area.x, area.y)];
+    assert_eq!(cell.bg, ratatui::style::Color::Yellow);
+}
+
+
+#[test]
+fn test_ui_status_message_in_footer() {
+    let backend = TestBackend::new(100, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    app.set_status("Couldn't open https://example.com", true);
+
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let content = buffer_to_string(terminal.backend().buffer());
+    let footer: Vec<&str> = content.lines().rev().take(3).collect();
+    assert!(footer.iter().any(|line| line.contains("Couldn't open https://example.com")));
+}
+
+#[test]
+fn test_ui_hyperlink_targets_cover_link_text() {
+    let backend = TestBackend::new(100, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    app.display_mode = DisplayMode::ProjectLinks;
+
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    assert!(app.hyperlink_targets.is_empty());
+
+    app.hyperlinks = true;
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    assert_eq!(app.hyperlink_targets.len(), app.project_links.len());
+    for ((area, url), link) in app.hyperlink_targets.iter().zip(&app.project_links) {
+        let rendered: String = (area.x..area.right()).map(|x| buffer[(x, area.y)].symbol().to_string()).collect();
+        assert_eq!(rendered, link.text);
+        assert_eq!(url, &link.url);
+    }
+
+    app.display_mode = DisplayMode::Contact;
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let links = app.section_links(DisplayMode::Contact);
+    assert!(!app.hyperlink_targets.is_empty());
+    assert!(app.hyperlink_targets.iter().all(|(_, url)| links.iter().any(|link| &link.url == url)));
+
+    app.palette.open = true;
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    assert!(app.hyperlink_targets.is_empty());
+}
+
+#[test]
+fn test_ui_uses_theme_colors() {
+    let backend = TestBackend::new(100, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    assert_eq!(terminal.backend().buffer()[(0, 0)].fg, ratatui::style::Color::Cyan);
+    assert_eq!(terminal.backend().buffer()[(0, 3)].fg, ratatui::style::Color::Blue);
+
+    app.theme_index = app.themes.iter().position(|theme| theme.name == "solarized").unwrap();
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let theme = app.theme().clone();
+    assert_eq!(terminal.backend().buffer()[(0, 0)].fg, theme.title);
+    assert_eq!(terminal.backend().buffer()[(0, 3)].fg, theme.border);
+}
+
+#[test]
+fn test_ui_without_color_relies_on_modifiers() {
+    let backend = TestBackend::new(100, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    app.color_depth = hire_david_parker::tui::theme::ColorDepth::Monochrome;
+    app.display_mode = DisplayMode::Contact;
+    app.menu_index = 4;
+    app.cycle_link_focus(true);
+
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    assert!(buffer.content.iter().all(|cell| cell.fg == ratatui::style::Color::Reset && cell.bg == ratatui::style::Color::Reset));
+
+    let menu_row = app.click_targets.iter().find(|(_, target)| *target == ClickTarget::MenuItem(4)).unwrap().0;
+    assert!(buffer[(menu_row.x, menu_row.y)].modifier.contains(ratatui::style::Modifier::REVERSED));
+
+    let links: Vec<_> = app.click_targets.iter().filter(|(_, target)| matches!(target, ClickTarget::InlineLink(..))).collect();
+    assert!(buffer[(links[0].0.x, links[0].0.y)].modifier.contains(ratatui::style::Modifier::REVERSED));
+    let other = links.iter().find(|(_, target)| *target != ClickTarget::InlineLink(DisplayMode::Contact, 0)).unwrap().0;
+    assert!(buffer[(other.x, other.y)].modifier.contains(ratatui::style::Modifier::UNDERLINED));
+}
+
+#[test]
+fn test_ui_downgrades_truecolor_themes() {
+    let backend = TestBackend::new(100, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    app.theme_index = app.themes.iter().position(|theme| theme.name == "solarized").unwrap();
+
+    app.color_depth = hire_david_parker::tui::theme::ColorDepth::Ansi256;
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    assert!(matches!(buffer[(0, 0)].fg, ratatui::style::Color::Indexed(_)));
+    assert!(!buffer.content.iter().any(|cell| matches!(cell.fg, ratatui::style::Color::Rgb(..))));
+
+    app.color_depth = hire_david_parker::tui::theme::ColorDepth::Ansi16;
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    assert!(!buffer.content.iter().any(|cell| matches!(cell.fg, ratatui::style::Color::Rgb(..) | ratatui::style::Color::Indexed(_))));
+}
+
+#[test]
+fn test_ui_footer_follows_keymap() {
+    let backend = TestBackend::new(100, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Menu;
+
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let content = buffer_to_string(terminal.backend().buffer());
+    assert!(content.contains("q: Quit | ?: Help | ↑/k: Up | ↓/j: Down | Enter: Select | /: Search | Ctrl-P: Commands | t: Theme"));
+
+    app.keymap = hire_david_parker::tui::keymap::Keymap::from_toml("[global]\nquit = \"x\"\nup = [\"w\", \"up\"]\ncycle_theme = []\n").unwrap();
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let content = buffer_to_string(terminal.backend().buffer());
+    assert!(content.contains("Esc: Quit | ?: Help | w/↑: Up | ↓/j: Down"));
+    assert!(!content.contains("Theme"));
+}
+
+#[test]
+fn test_ui_titles_name_the_bound_keys() {
+    let backend = TestBackend::new(120, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Skills;
+
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    assert!(buffer_to_string(terminal.backend().buffer()).contains("Skills (→ for bar graphs)"));
+
+    app.keymap = hire_david_parker::tui::keymap::Keymap::from_toml(
+        "[content]\nright = \"d\"\n[timeline]\nleft = \"a\"\nright = \"s\"\nnext_filter = \"g\"\n",
+    )
+    .unwrap();
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    assert!(buffer_to_string(terminal.backend().buffer()).contains("Skills (d for bar graphs)"));
+
+    app.display_mode = DisplayMode::Timeline;
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let content = buffer_to_string(terminal.backend().buffer());
+    assert!(content.contains("Navigate with a s | Filter: All (g)"));
+
+    app.display_mode = DisplayMode::About;
+    app.keymap = hire_david_parker::tui::keymap::Keymap::from_toml("[global]\nnext_link = \"ctrl-n\"\n").unwrap();
+    app.yank();
+    assert_eq!(app.status.as_ref().unwrap().text, "Nothing to copy here. Press Ctrl-N to focus a link first.");
+}
+
+#[test]
+fn test_ui_help_overlay() {
+    let backend = TestBackend::new(100, 40);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::new();
+    app.display_mode = DisplayMode::Timeline;
+    app.help = Some(Default::default());
+
+    terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+    let content = buffer_to_string(terminal.backend().buffer());
+    assert!(content.contains("Help: Timeline"));
+    assert!(content.contains("Navigation"));
+    assert!(content.contains("Show the event details"));
+    assert!(content.contains("Esc/Backspace"));
+    assert!(content.contains("Scroll | Esc/?: Close"));
+    assert!(app.hyperlink_targets.is_empty());
+}
+
 fn buffer_to_string(buffer: &Buffer) -> String {
     let mut result = String::new();
     for row in 0..buffer.area.height {